@@ -59,24 +59,30 @@ fn run_single_threaded(args: Args) {
     let mut ctxs = Vec::new();
     for _ in 0..num {
         let ctx = connect_to_db(url.clone());
+        subscribe_to_tables(&ctx);
         ctxs.push(ctx);
     }
 
     let sleep_duration = std::time::Duration::from_millis(300);
 
     let mut rng = rand::rng();
+    // every bot has its own identity, so they can share the input sequence numbers
+    let mut seq = 0;
     loop {
+        seq += 1;
         for ctx in &ctxs {
+            // process received messages, so the game config arrives
+            ctx.frame_tick().unwrap();
+            let max_impulse = max_impulse(ctx);
             // pick a ranom direction on the unit circle
             let angle = rand::random::<f64>() * 2.0 * std::f64::consts::PI;
             // pick a random magnitude
-            let magnitude = rng.random::<f64>() * 3.0;
+            let magnitude = rng.random::<f64>() * 0.15 * max_impulse;
             let impulse_x = angle.cos() * magnitude;
             let impulse_y = angle.sin() * magnitude;
 
             if args.reducers {
-                ctx.reducers.apply_impulse(impulse_x, impulse_y).unwrap();
-                ctx.frame_tick().unwrap();
+                ctx.reducers.apply_impulse(seq, impulse_x, impulse_y).unwrap();
             }
         }
         thread::sleep(sleep_duration);
@@ -98,6 +104,7 @@ fn multiplayer_loop(url: String) {
 
     let mut rng = rand::thread_rng();
     let sleep_duration = std::time::Duration::from_millis(1000);
+    let mut seq = 0;
 
     // Handle input
     loop {
@@ -111,11 +118,12 @@ fn multiplayer_loop(url: String) {
         // pick a ranom direction on the unit circle
         let angle = rand::random::<f64>() * 2.0 * std::f64::consts::PI;
         // pick a random magnitude
-        let magnitude = rng.random::<f64>() * 3.0;
+        let magnitude = rng.random::<f64>() * 0.15 * max_impulse(&ctx);
         let impulse_x = angle.cos() * magnitude;
         let impulse_y = angle.sin() * magnitude;
 
-        ctx.reducers.apply_impulse(impulse_x, impulse_y).unwrap()
+        seq += 1;
+        ctx.reducers.apply_impulse(seq, impulse_x, impulse_y).unwrap()
     }
 }
 
@@ -145,8 +153,17 @@ fn register_callbacks(ctx: &DbConnection) {}
 
 /// Register subscriptions for all rows of both tables.
 fn subscribe_to_tables(ctx: &DbConnection) {
-    // ctx.subscription_builder()
-    //     .subscribe(["SELECT * FROM balls"]);
+    ctx.subscription_builder()
+        .subscribe(["SELECT * FROM game_config"]);
+}
+
+/// The `max_impulse` of the server, or the physics default until its config arrived.
+fn max_impulse(ctx: &DbConnection) -> f64 {
+    // the config has a single row, see `GameConfig::ID` on the server
+    match ctx.db.game_config().id().find(&0) {
+        Some(config) => config.max_impulse,
+        None => tagars_physics::Config::default().max_impulse,
+    }
 }
 
 /// Our `on_connect` callback: save our credentials to a file.
@@ -7,6 +7,7 @@ use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
 #[sats(crate = __lib)]
 pub(super) struct ApplyImpulseArgs {
+    pub seq: u64,
     pub impulse_x: f64,
     pub impulse_y: f64,
 }
@@ -14,6 +15,7 @@ pub(super) struct ApplyImpulseArgs {
 impl From<ApplyImpulseArgs> for super::Reducer {
     fn from(args: ApplyImpulseArgs) -> Self {
         Self::ApplyImpulse {
+            seq: args.seq,
             impulse_x: args.impulse_x,
             impulse_y: args.impulse_y,
         }
@@ -36,7 +38,7 @@ pub trait apply_impulse {
     /// This method returns immediately, and errors only if we are unable to send the request.
     /// The reducer will run asynchronously in the future,
     ///  and its status can be observed by listening for [`Self::on_apply_impulse`] callbacks.
-    fn apply_impulse(&self, impulse_x: f64, impulse_y: f64) -> __sdk::Result<()>;
+    fn apply_impulse(&self, seq: u64, impulse_x: f64, impulse_y: f64) -> __sdk::Result<()>;
     /// Register a callback to run whenever we are notified of an invocation of the reducer `apply_impulse`.
     ///
     /// Callbacks should inspect the [`__sdk::ReducerEvent`] contained in the [`super::ReducerEventContext`]
@@ -46,7 +48,7 @@ pub trait apply_impulse {
     /// to cancel the callback.
     fn on_apply_impulse(
         &self,
-        callback: impl FnMut(&super::ReducerEventContext, &f64, &f64) + Send + 'static,
+        callback: impl FnMut(&super::ReducerEventContext, &u64, &f64, &f64) + Send + 'static,
     ) -> ApplyImpulseCallbackId;
     /// Cancel a callback previously registered by [`Self::on_apply_impulse`],
     /// causing it not to run in the future.
@@ -54,10 +56,11 @@ pub trait apply_impulse {
 }
 
 impl apply_impulse for super::RemoteReducers {
-    fn apply_impulse(&self, impulse_x: f64, impulse_y: f64) -> __sdk::Result<()> {
+    fn apply_impulse(&self, seq: u64, impulse_x: f64, impulse_y: f64) -> __sdk::Result<()> {
         self.imp.call_reducer(
             "apply_impulse",
             ApplyImpulseArgs {
+                seq,
                 impulse_x,
                 impulse_y,
             },
@@ -65,7 +68,7 @@ impl apply_impulse for super::RemoteReducers {
     }
     fn on_apply_impulse(
         &self,
-        mut callback: impl FnMut(&super::ReducerEventContext, &f64, &f64) + Send + 'static,
+        mut callback: impl FnMut(&super::ReducerEventContext, &u64, &f64, &f64) + Send + 'static,
     ) -> ApplyImpulseCallbackId {
         ApplyImpulseCallbackId(self.imp.on_reducer(
             "apply_impulse",
@@ -75,6 +78,7 @@ impl apply_impulse for super::RemoteReducers {
                         __sdk::ReducerEvent {
                             reducer:
                                 super::Reducer::ApplyImpulse {
+                                    seq,
                                     impulse_x,
                                     impulse_y,
                                 },
@@ -85,7 +89,7 @@ impl apply_impulse for super::RemoteReducers {
                 else {
                     unreachable!()
                 };
-                callback(ctx, impulse_x, impulse_y)
+                callback(ctx, seq, impulse_x, impulse_y)
             }),
         ))
     }
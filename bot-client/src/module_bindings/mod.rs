@@ -4,47 +4,115 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+pub mod admin_type;
+pub mod admins_table;
 pub mod apply_impulse_reducer;
 pub mod ball_type;
 pub mod balls_table;
+pub mod ban_identity_reducer;
+pub mod ban_type;
+pub mod banned_table;
+pub mod broadphase_kind_type;
+pub mod clear_foods_reducer;
+pub mod eject_mass_reducer;
 pub mod food_type;
 pub mod foods_table;
+pub mod game_config_table;
+pub mod game_config_type;
 pub mod identity_connected_reducer;
 pub mod identity_disconnected_reducer;
+pub mod input_limit_type;
+pub mod input_limits_table;
+pub mod kick_player_reducer;
+pub mod leaderboard_entry_type;
+pub mod leaderboard_table;
+pub mod pending_input_type;
+pub mod pending_inputs_table;
+pub mod physics_tick_type;
+pub mod physics_ticks_table;
+pub mod player_type;
+pub mod players_table;
+pub mod reset_world_reducer;
 pub mod respawn_ball_reducer;
 pub mod respawn_balls_schedule_table;
 pub mod respawn_balls_schedule_type;
 pub mod rgb_type;
+pub mod set_color_reducer;
+pub mod set_config_reducer;
+pub mod set_name_reducer;
 pub mod spawn_food_reducer;
 pub mod spawn_food_schedule_type;
 pub mod spawn_foods_schedule_table;
+pub mod split_reducer;
+pub mod unban_identity_reducer;
 pub mod update_balls_reducer;
 pub mod update_balls_schedule_table;
 pub mod update_balls_schedule_type;
-
+pub mod update_leaderboard_reducer;
+pub mod update_leaderboard_schedule_table;
+pub mod update_leaderboard_schedule_type;
+pub mod virus_type;
+pub mod viruses_table;
+
+pub use admin_type::Admin;
+pub use admins_table::*;
 pub use apply_impulse_reducer::{
-    ApplyImpulseCallbackId, apply_impulse, set_flags_for_apply_impulse,
+    apply_impulse, set_flags_for_apply_impulse, ApplyImpulseCallbackId,
 };
 pub use ball_type::Ball;
 pub use balls_table::*;
+pub use ban_identity_reducer::{ban_identity, set_flags_for_ban_identity, BanIdentityCallbackId};
+pub use ban_type::Ban;
+pub use banned_table::*;
+pub use broadphase_kind_type::BroadphaseKind;
+pub use clear_foods_reducer::{clear_foods, set_flags_for_clear_foods, ClearFoodsCallbackId};
+pub use eject_mass_reducer::{eject_mass, set_flags_for_eject_mass, EjectMassCallbackId};
 pub use food_type::Food;
 pub use foods_table::*;
+pub use game_config_table::*;
+pub use game_config_type::GameConfig;
 pub use identity_connected_reducer::{
-    IdentityConnectedCallbackId, identity_connected, set_flags_for_identity_connected,
+    identity_connected, set_flags_for_identity_connected, IdentityConnectedCallbackId,
 };
 pub use identity_disconnected_reducer::{
-    IdentityDisconnectedCallbackId, identity_disconnected, set_flags_for_identity_disconnected,
+    identity_disconnected, set_flags_for_identity_disconnected, IdentityDisconnectedCallbackId,
 };
-pub use respawn_ball_reducer::{RespawnBallCallbackId, respawn_ball, set_flags_for_respawn_ball};
+pub use input_limit_type::InputLimit;
+pub use input_limits_table::*;
+pub use kick_player_reducer::{kick_player, set_flags_for_kick_player, KickPlayerCallbackId};
+pub use leaderboard_entry_type::LeaderboardEntry;
+pub use leaderboard_table::*;
+pub use pending_input_type::PendingInput;
+pub use pending_inputs_table::*;
+pub use physics_tick_type::PhysicsTick;
+pub use physics_ticks_table::*;
+pub use player_type::Player;
+pub use players_table::*;
+pub use reset_world_reducer::{reset_world, set_flags_for_reset_world, ResetWorldCallbackId};
+pub use respawn_ball_reducer::{respawn_ball, set_flags_for_respawn_ball, RespawnBallCallbackId};
 pub use respawn_balls_schedule_table::*;
 pub use respawn_balls_schedule_type::RespawnBallsSchedule;
 pub use rgb_type::Rgb;
-pub use spawn_food_reducer::{SpawnFoodCallbackId, set_flags_for_spawn_food, spawn_food};
+pub use set_color_reducer::{set_color, set_flags_for_set_color, SetColorCallbackId};
+pub use set_config_reducer::{set_config, set_flags_for_set_config, SetConfigCallbackId};
+pub use set_name_reducer::{set_flags_for_set_name, set_name, SetNameCallbackId};
+pub use spawn_food_reducer::{set_flags_for_spawn_food, spawn_food, SpawnFoodCallbackId};
 pub use spawn_food_schedule_type::SpawnFoodSchedule;
 pub use spawn_foods_schedule_table::*;
-pub use update_balls_reducer::{UpdateBallsCallbackId, set_flags_for_update_balls, update_balls};
+pub use split_reducer::{set_flags_for_split, split, SplitCallbackId};
+pub use unban_identity_reducer::{
+    set_flags_for_unban_identity, unban_identity, UnbanIdentityCallbackId,
+};
+pub use update_balls_reducer::{set_flags_for_update_balls, update_balls, UpdateBallsCallbackId};
 pub use update_balls_schedule_table::*;
 pub use update_balls_schedule_type::UpdateBallsSchedule;
+pub use update_leaderboard_reducer::{
+    set_flags_for_update_leaderboard, update_leaderboard, UpdateLeaderboardCallbackId,
+};
+pub use update_leaderboard_schedule_table::*;
+pub use update_leaderboard_schedule_type::UpdateLeaderboardSchedule;
+pub use virus_type::Virus;
+pub use viruses_table::*;
 
 #[derive(Clone, PartialEq, Debug)]
 
@@ -54,12 +122,54 @@ pub use update_balls_schedule_type::UpdateBallsSchedule;
 /// to indicate which reducer caused the event.
 
 pub enum Reducer {
-    ApplyImpulse { impulse_x: f64, impulse_y: f64 },
+    ApplyImpulse {
+        seq: u64,
+        impulse_x: f64,
+        impulse_y: f64,
+    },
+    BanIdentity {
+        identity: __sdk::Identity,
+        duration_micros: Option<i64>,
+    },
+    ClearFoods,
+    EjectMass {
+        aim_x: f64,
+        aim_y: f64,
+    },
     IdentityConnected,
     IdentityDisconnected,
-    RespawnBall { schedule: RespawnBallsSchedule },
-    SpawnFood { schedule: SpawnFoodSchedule },
-    UpdateBalls { schedule: UpdateBallsSchedule },
+    KickPlayer {
+        identity: __sdk::Identity,
+    },
+    ResetWorld,
+    RespawnBall {
+        schedule: RespawnBallsSchedule,
+    },
+    SetColor {
+        color: Rgb,
+    },
+    SetConfig {
+        config: GameConfig,
+    },
+    SetName {
+        name: String,
+    },
+    SpawnFood {
+        schedule: SpawnFoodSchedule,
+    },
+    Split {
+        aim_x: f64,
+        aim_y: f64,
+    },
+    UnbanIdentity {
+        identity: __sdk::Identity,
+    },
+    UpdateBalls {
+        schedule: UpdateBallsSchedule,
+    },
+    UpdateLeaderboard {
+        schedule: UpdateLeaderboardSchedule,
+    },
 }
 
 impl __sdk::InModule for Reducer {
@@ -70,11 +180,22 @@ impl __sdk::Reducer for Reducer {
     fn reducer_name(&self) -> &'static str {
         match self {
             Reducer::ApplyImpulse { .. } => "apply_impulse",
+            Reducer::BanIdentity { .. } => "ban_identity",
+            Reducer::ClearFoods => "clear_foods",
+            Reducer::EjectMass { .. } => "eject_mass",
             Reducer::IdentityConnected => "identity_connected",
             Reducer::IdentityDisconnected => "identity_disconnected",
+            Reducer::KickPlayer { .. } => "kick_player",
+            Reducer::ResetWorld => "reset_world",
             Reducer::RespawnBall { .. } => "respawn_ball",
+            Reducer::SetColor { .. } => "set_color",
+            Reducer::SetConfig { .. } => "set_config",
+            Reducer::SetName { .. } => "set_name",
             Reducer::SpawnFood { .. } => "spawn_food",
+            Reducer::Split { .. } => "split",
+            Reducer::UnbanIdentity { .. } => "unban_identity",
             Reducer::UpdateBalls { .. } => "update_balls",
+            Reducer::UpdateLeaderboard { .. } => "update_leaderboard",
         }
     }
 }
@@ -86,6 +207,27 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 apply_impulse_reducer::ApplyImpulseArgs,
             >("apply_impulse", &value.args)?
             .into()),
+            "ban_identity" => Ok(
+                __sdk::parse_reducer_args::<ban_identity_reducer::BanIdentityArgs>(
+                    "ban_identity",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "clear_foods" => Ok(
+                __sdk::parse_reducer_args::<clear_foods_reducer::ClearFoodsArgs>(
+                    "clear_foods",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "eject_mass" => Ok(
+                __sdk::parse_reducer_args::<eject_mass_reducer::EjectMassArgs>(
+                    "eject_mass",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "identity_connected" => Ok(__sdk::parse_reducer_args::<
                 identity_connected_reducer::IdentityConnectedArgs,
             >("identity_connected", &value.args)?
@@ -94,6 +236,20 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 identity_disconnected_reducer::IdentityDisconnectedArgs,
             >("identity_disconnected", &value.args)?
             .into()),
+            "kick_player" => Ok(
+                __sdk::parse_reducer_args::<kick_player_reducer::KickPlayerArgs>(
+                    "kick_player",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "reset_world" => Ok(
+                __sdk::parse_reducer_args::<reset_world_reducer::ResetWorldArgs>(
+                    "reset_world",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "respawn_ball" => Ok(
                 __sdk::parse_reducer_args::<respawn_ball_reducer::RespawnBallArgs>(
                     "respawn_ball",
@@ -101,6 +257,25 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "set_color" => Ok(
+                __sdk::parse_reducer_args::<set_color_reducer::SetColorArgs>(
+                    "set_color",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "set_config" => Ok(
+                __sdk::parse_reducer_args::<set_config_reducer::SetConfigArgs>(
+                    "set_config",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "set_name" => Ok(__sdk::parse_reducer_args::<set_name_reducer::SetNameArgs>(
+                "set_name",
+                &value.args,
+            )?
+            .into()),
             "spawn_food" => Ok(
                 __sdk::parse_reducer_args::<spawn_food_reducer::SpawnFoodArgs>(
                     "spawn_food",
@@ -108,6 +283,15 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "split" => Ok(__sdk::parse_reducer_args::<split_reducer::SplitArgs>(
+                "split",
+                &value.args,
+            )?
+            .into()),
+            "unban_identity" => Ok(__sdk::parse_reducer_args::<
+                unban_identity_reducer::UnbanIdentityArgs,
+            >("unban_identity", &value.args)?
+            .into()),
             "update_balls" => Ok(
                 __sdk::parse_reducer_args::<update_balls_reducer::UpdateBallsArgs>(
                     "update_balls",
@@ -115,6 +299,10 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "update_leaderboard" => Ok(__sdk::parse_reducer_args::<
+                update_leaderboard_reducer::UpdateLeaderboardArgs,
+            >("update_leaderboard", &value.args)?
+            .into()),
             unknown => {
                 Err(
                     __sdk::InternalError::unknown_name("reducer", unknown, "ReducerCallInfo")
@@ -129,11 +317,21 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct DbUpdate {
+    admins: __sdk::TableUpdate<Admin>,
     balls: __sdk::TableUpdate<Ball>,
+    banned: __sdk::TableUpdate<Ban>,
     foods: __sdk::TableUpdate<Food>,
+    game_config: __sdk::TableUpdate<GameConfig>,
+    input_limits: __sdk::TableUpdate<InputLimit>,
+    leaderboard: __sdk::TableUpdate<LeaderboardEntry>,
+    pending_inputs: __sdk::TableUpdate<PendingInput>,
+    physics_ticks: __sdk::TableUpdate<PhysicsTick>,
+    players: __sdk::TableUpdate<Player>,
     respawn_balls_schedule: __sdk::TableUpdate<RespawnBallsSchedule>,
     spawn_foods_schedule: __sdk::TableUpdate<SpawnFoodSchedule>,
     update_balls_schedule: __sdk::TableUpdate<UpdateBallsSchedule>,
+    update_leaderboard_schedule: __sdk::TableUpdate<UpdateLeaderboardSchedule>,
+    viruses: __sdk::TableUpdate<Virus>,
 }
 
 impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
@@ -142,8 +340,27 @@ impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
         let mut db_update = DbUpdate::default();
         for table_update in raw.tables {
             match &table_update.table_name[..] {
+                "admins" => db_update.admins = admins_table::parse_table_update(table_update)?,
                 "balls" => db_update.balls = balls_table::parse_table_update(table_update)?,
+                "banned" => db_update.banned = banned_table::parse_table_update(table_update)?,
                 "foods" => db_update.foods = foods_table::parse_table_update(table_update)?,
+                "game_config" => {
+                    db_update.game_config = game_config_table::parse_table_update(table_update)?
+                }
+                "input_limits" => {
+                    db_update.input_limits = input_limits_table::parse_table_update(table_update)?
+                }
+                "leaderboard" => {
+                    db_update.leaderboard = leaderboard_table::parse_table_update(table_update)?
+                }
+                "pending_inputs" => {
+                    db_update.pending_inputs =
+                        pending_inputs_table::parse_table_update(table_update)?
+                }
+                "physics_ticks" => {
+                    db_update.physics_ticks = physics_ticks_table::parse_table_update(table_update)?
+                }
+                "players" => db_update.players = players_table::parse_table_update(table_update)?,
                 "respawn_balls_schedule" => {
                     db_update.respawn_balls_schedule =
                         respawn_balls_schedule_table::parse_table_update(table_update)?
@@ -156,6 +373,11 @@ impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
                     db_update.update_balls_schedule =
                         update_balls_schedule_table::parse_table_update(table_update)?
                 }
+                "update_leaderboard_schedule" => {
+                    db_update.update_leaderboard_schedule =
+                        update_leaderboard_schedule_table::parse_table_update(table_update)?
+                }
+                "viruses" => db_update.viruses = viruses_table::parse_table_update(table_update)?,
 
                 unknown => {
                     return Err(__sdk::InternalError::unknown_name(
@@ -182,12 +404,36 @@ impl __sdk::DbUpdate for DbUpdate {
     ) -> AppliedDiff<'_> {
         let mut diff = AppliedDiff::default();
 
+        diff.admins = cache
+            .apply_diff_to_table::<Admin>("admins", &self.admins)
+            .with_updates_by_pk(|row| &row.identity);
         diff.balls = cache
             .apply_diff_to_table::<Ball>("balls", &self.balls)
+            .with_updates_by_pk(|row| &row.id);
+        diff.banned = cache
+            .apply_diff_to_table::<Ban>("banned", &self.banned)
             .with_updates_by_pk(|row| &row.identity);
         diff.foods = cache
             .apply_diff_to_table::<Food>("foods", &self.foods)
             .with_updates_by_pk(|row| &row.id);
+        diff.game_config = cache
+            .apply_diff_to_table::<GameConfig>("game_config", &self.game_config)
+            .with_updates_by_pk(|row| &row.id);
+        diff.input_limits = cache
+            .apply_diff_to_table::<InputLimit>("input_limits", &self.input_limits)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.leaderboard = cache
+            .apply_diff_to_table::<LeaderboardEntry>("leaderboard", &self.leaderboard)
+            .with_updates_by_pk(|row| &row.owner);
+        diff.pending_inputs = cache
+            .apply_diff_to_table::<PendingInput>("pending_inputs", &self.pending_inputs)
+            .with_updates_by_pk(|row| &row.owner);
+        diff.physics_ticks = cache
+            .apply_diff_to_table::<PhysicsTick>("physics_ticks", &self.physics_ticks)
+            .with_updates_by_pk(|row| &row.tick_id);
+        diff.players = cache
+            .apply_diff_to_table::<Player>("players", &self.players)
+            .with_updates_by_pk(|row| &row.identity);
         diff.respawn_balls_schedule = cache
             .apply_diff_to_table::<RespawnBallsSchedule>(
                 "respawn_balls_schedule",
@@ -206,6 +452,15 @@ impl __sdk::DbUpdate for DbUpdate {
                 &self.update_balls_schedule,
             )
             .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.update_leaderboard_schedule = cache
+            .apply_diff_to_table::<UpdateLeaderboardSchedule>(
+                "update_leaderboard_schedule",
+                &self.update_leaderboard_schedule,
+            )
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.viruses = cache
+            .apply_diff_to_table::<Virus>("viruses", &self.viruses)
+            .with_updates_by_pk(|row| &row.id);
 
         diff
     }
@@ -215,11 +470,21 @@ impl __sdk::DbUpdate for DbUpdate {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct AppliedDiff<'r> {
+    admins: __sdk::TableAppliedDiff<'r, Admin>,
     balls: __sdk::TableAppliedDiff<'r, Ball>,
+    banned: __sdk::TableAppliedDiff<'r, Ban>,
     foods: __sdk::TableAppliedDiff<'r, Food>,
+    game_config: __sdk::TableAppliedDiff<'r, GameConfig>,
+    input_limits: __sdk::TableAppliedDiff<'r, InputLimit>,
+    leaderboard: __sdk::TableAppliedDiff<'r, LeaderboardEntry>,
+    pending_inputs: __sdk::TableAppliedDiff<'r, PendingInput>,
+    physics_ticks: __sdk::TableAppliedDiff<'r, PhysicsTick>,
+    players: __sdk::TableAppliedDiff<'r, Player>,
     respawn_balls_schedule: __sdk::TableAppliedDiff<'r, RespawnBallsSchedule>,
     spawn_foods_schedule: __sdk::TableAppliedDiff<'r, SpawnFoodSchedule>,
     update_balls_schedule: __sdk::TableAppliedDiff<'r, UpdateBallsSchedule>,
+    update_leaderboard_schedule: __sdk::TableAppliedDiff<'r, UpdateLeaderboardSchedule>,
+    viruses: __sdk::TableAppliedDiff<'r, Virus>,
 }
 
 impl __sdk::InModule for AppliedDiff<'_> {
@@ -232,8 +497,32 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
         event: &EventContext,
         callbacks: &mut __sdk::DbCallbacks<RemoteModule>,
     ) {
+        callbacks.invoke_table_row_callbacks::<Admin>("admins", &self.admins, event);
         callbacks.invoke_table_row_callbacks::<Ball>("balls", &self.balls, event);
+        callbacks.invoke_table_row_callbacks::<Ban>("banned", &self.banned, event);
         callbacks.invoke_table_row_callbacks::<Food>("foods", &self.foods, event);
+        callbacks.invoke_table_row_callbacks::<GameConfig>("game_config", &self.game_config, event);
+        callbacks.invoke_table_row_callbacks::<InputLimit>(
+            "input_limits",
+            &self.input_limits,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<LeaderboardEntry>(
+            "leaderboard",
+            &self.leaderboard,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<PendingInput>(
+            "pending_inputs",
+            &self.pending_inputs,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<PhysicsTick>(
+            "physics_ticks",
+            &self.physics_ticks,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Player>("players", &self.players, event);
         callbacks.invoke_table_row_callbacks::<RespawnBallsSchedule>(
             "respawn_balls_schedule",
             &self.respawn_balls_schedule,
@@ -249,6 +538,12 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
             &self.update_balls_schedule,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<UpdateLeaderboardSchedule>(
+            "update_leaderboard_schedule",
+            &self.update_leaderboard_schedule,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Virus>("viruses", &self.viruses, event);
     }
 }
 
@@ -491,21 +786,21 @@ impl __sdk::SubscriptionHandle for SubscriptionHandle {
 /// either a [`DbConnection`] or an [`EventContext`] and operate on either.
 pub trait RemoteDbContext:
     __sdk::DbContext<
-        DbView = RemoteTables,
-        Reducers = RemoteReducers,
-        SetReducerFlags = SetReducerFlags,
-        SubscriptionBuilder = __sdk::SubscriptionBuilder<RemoteModule>,
-    >
+    DbView = RemoteTables,
+    Reducers = RemoteReducers,
+    SetReducerFlags = SetReducerFlags,
+    SubscriptionBuilder = __sdk::SubscriptionBuilder<RemoteModule>,
+>
 {
 }
 impl<
-    Ctx: __sdk::DbContext<
+        Ctx: __sdk::DbContext<
             DbView = RemoteTables,
             Reducers = RemoteReducers,
             SetReducerFlags = SetReducerFlags,
             SubscriptionBuilder = __sdk::SubscriptionBuilder<RemoteModule>,
         >,
-> RemoteDbContext for Ctx
+    > RemoteDbContext for Ctx
 {
 }
 
@@ -824,10 +1119,20 @@ impl __sdk::SpacetimeModule for RemoteModule {
     type SubscriptionHandle = SubscriptionHandle;
 
     fn register_tables(client_cache: &mut __sdk::ClientCache<Self>) {
+        admins_table::register_table(client_cache);
         balls_table::register_table(client_cache);
+        banned_table::register_table(client_cache);
         foods_table::register_table(client_cache);
+        game_config_table::register_table(client_cache);
+        input_limits_table::register_table(client_cache);
+        leaderboard_table::register_table(client_cache);
+        pending_inputs_table::register_table(client_cache);
+        physics_ticks_table::register_table(client_cache);
+        players_table::register_table(client_cache);
         respawn_balls_schedule_table::register_table(client_cache);
         spawn_foods_schedule_table::register_table(client_cache);
         update_balls_schedule_table::register_table(client_cache);
+        update_leaderboard_schedule_table::register_table(client_cache);
+        viruses_table::register_table(client_cache);
     }
 }
@@ -1,65 +1,79 @@
 use crate::GameState;
-use crate::multiplayer::Ball;
+use clap::ValueEnum;
+use std::time::{Duration, Instant};
 use teng::components::Component;
 use teng::{SharedState, UpdateInfo};
 
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InterpolationMode {
+    /// Render the most recent state
+    Off,
+    /// Interpolate between the states around the render time
+    On,
+    /// Interpolate, and extrapolate with velocity and drag when updates stall
+    Extrapolate,
+}
+
+/// Produces the balls to render from the buffered snapshots.
+///
+/// With interpolation, balls are rendered `render_delay` behind the estimated server time, so we
+/// usually have a snapshot on both sides of the rendered time even if ticks arrive irregularly.
 pub struct BallsInterpolatorComponent {
-    do_interpolation: bool,
+    mode: InterpolationMode,
+    render_delay: Duration,
+    last_update: Option<Instant>,
 }
 
 impl BallsInterpolatorComponent {
-    pub fn new(do_interpolation: bool) -> Self {
-        Self { do_interpolation }
+    pub fn new(mode: InterpolationMode, render_delay: Duration) -> Self {
+        Self {
+            mode,
+            render_delay,
+            last_update: None,
+        }
+    }
+
+    fn render_time(&self, game_state: &GameState, now: Instant) -> Option<i64> {
+        let server_now = game_state.server_clock.server_micros_at(now)?;
+        Some(server_now - self.render_delay.as_micros() as i64)
     }
 }
 
 impl Component<GameState> for BallsInterpolatorComponent {
     fn update(&mut self, update_info: UpdateInfo, shared_state: &mut SharedState<GameState>) {
         let game_state = &mut shared_state.custom;
-        if !self.do_interpolation {
+        let now = update_info.current_time;
+        let frame_time = self.last_update.map_or(Duration::ZERO, |last| now - last);
+        self.last_update = Some(now);
+
+        let render_time = self.render_time(game_state, now);
+        let (Some(render_time), InterpolationMode::On | InterpolationMode::Extrapolate) =
+            (render_time, self.mode)
+        else {
             // simply copy over the most recent state to the output field
-            for (identity, old_and_new_ball) in game_state.raw_balls.iter() {
-                game_state
-                    .balls
-                    .insert(identity.clone(), old_and_new_ball.new.clone());
+            for (id, snapshots) in game_state.raw_balls.iter_mut() {
+                snapshots.prune_before(i64::MAX);
+                if let Some((_, ball)) = snapshots.latest() {
+                    game_state.balls.insert(*id, ball.clone());
+                }
             }
-        } else {
-            const PHYSICS_TICKS_PER_SECOND: f64 = 60.0;
-            //TODO: use the diff between old and new times as tick duration. currently we dont interpolate if the server is slow.
-            // ah, we don't actually get this. We use client-side time to determine old timestamp.
-            // we don't use server-side because the clocks differ, so we can't interpolate properly.
-            // we could store a mapping from old_server_side to client_side_when_old_received.
-            let physics_tick_duration = 1.0 / PHYSICS_TICKS_PER_SECOND;
-
-            // interpolate between old and new balls
-            for (identity, old_and_new_ball) in game_state.raw_balls.iter() {
-                let old_ball = &old_and_new_ball.old;
-                let new_ball = &old_and_new_ball.new;
-                let timestamp_at_old = old_and_new_ball.timestamp_at_old;
-                let timestamp_now = update_info.current_time;
-                let time_since_old = (timestamp_now - timestamp_at_old).as_secs_f64();
-                // if you comment this out, you get some extrapolation if the server is running below PHYSICS_TICKS_PER_SECOND
-                let time_since_old = time_since_old.min(physics_tick_duration);
-
-                let fraction = time_since_old / physics_tick_duration;
-
-                // only interpolate x and y for now
-                let x = old_ball.x + fraction * (new_ball.x - old_ball.x);
-                let y = old_ball.y + fraction * (new_ball.y - old_ball.y);
-                // let vx = old_ball.vx + fraction * (new_ball.vx - old_ball.vx);
-                // let vy = old_ball.vy + fraction * (new_ball.vy - old_ball.vy);
-                // let radius = old_ball.radius + fraction * (new_ball.radius - old_ball.radius);
-                // let color = old_ball.color.interpolate(new_ball.color, fraction);
-
-                let interpolated_ball = Ball {
-                    x,
-                    y,
-                    // to avoid interpolating across the screen when the player respawns
-                    dead: old_ball.dead || new_ball.dead,
-                    ..new_ball.clone()
-                };
+            return;
+        };
+        let config = game_state
+            .game_config
+            .as_ref()
+            .map(tagars_physics::Config::from);
 
-                game_state.balls.insert(identity.clone(), interpolated_ball);
+        for (id, snapshots) in game_state.raw_balls.iter_mut() {
+            snapshots.prune_before(render_time);
+            let ball = match (&config, self.mode) {
+                (Some(config), InterpolationMode::Extrapolate) => {
+                    snapshots.extrapolate_at(render_time, config, frame_time)
+                }
+                _ => snapshots.interpolate_at(render_time),
+            };
+            if let Some(ball) = ball {
+                game_state.balls.insert(*id, ball);
             }
         }
     }
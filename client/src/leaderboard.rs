@@ -0,0 +1,68 @@
+use crate::GameState;
+use crate::multiplayer::LeaderboardEntry;
+use teng::SharedState;
+use teng::components::Component;
+use teng::rendering::render::Render;
+use teng::rendering::renderer::Renderer;
+
+/// Renders the server-maintained leaderboard in the top right corner.
+pub struct LeaderboardComponent;
+
+impl LeaderboardComponent {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn format_entry(game_state: &GameState, entry: &LeaderboardEntry, is_ours: bool) -> String {
+        let marker = if is_ours { '>' } else { ' ' };
+        let name = game_state
+            .players
+            .get(&entry.owner)
+            .map(|player| player.name.as_str())
+            .unwrap_or("?");
+        format!("{marker}{:>3}. {name:<16} {:>6}", entry.rank, entry.score)
+    }
+}
+
+impl Component<GameState> for LeaderboardComponent {
+    fn render(
+        &self,
+        renderer: &mut dyn Renderer,
+        shared_state: &SharedState<GameState>,
+        depth_base: i32,
+    ) {
+        let game_state = &shared_state.custom;
+        let our_identity = game_state.our_identity.as_ref();
+
+        let mut top = game_state
+            .leaderboard
+            .values()
+            .filter(|entry| entry.rank <= tagars_physics::LEADERBOARD_TOP_N)
+            .collect::<Vec<_>>();
+        top.sort_by_key(|entry| entry.rank);
+
+        let mut lines = vec![" Leaderboard".to_string()];
+        for entry in top {
+            let is_ours = Some(&entry.owner) == our_identity;
+            lines.push(Self::format_entry(game_state, entry, is_ours));
+        }
+        // show our own rank even if we're not in the top N
+        if let Some(entry) = our_identity.and_then(|identity| game_state.leaderboard.get(identity))
+        {
+            if entry.rank > tagars_physics::LEADERBOARD_TOP_N {
+                lines.push("  ...".to_string());
+                lines.push(Self::format_entry(game_state, entry, true));
+            }
+        }
+
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let x = shared_state.display_info.width().saturating_sub(width + 1);
+        for (y, line) in lines.iter().enumerate() {
+            line.render(renderer, x, y, depth_base);
+        }
+    }
+}
@@ -1,11 +1,16 @@
-use crate::balls_interpolator::BallsInterpolatorComponent;
-use crate::multiplayer::{Ball, Food, ReceiveMessage, SendMessage};
+use crate::balls_interpolator::{BallsInterpolatorComponent, InterpolationMode};
+use crate::leaderboard::LeaderboardComponent;
+use crate::multiplayer::{
+    Ball, Food, GameConfig, LeaderboardEntry, Player, ReceiveMessage, SendMessage, Virus,
+};
+use crate::prediction::{PredictionComponent, SentInput};
 use crate::slingshot::SlingshotComponent;
+use crate::snapshots::{BallSnapshots, ServerClock};
 use crate::world::{World, WorldComponent};
 use clap::Parser;
 use crossterm::event::KeyCode;
 use spacetimedb_sdk::{Identity, Timestamp};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::{io, thread};
 use std::io::stdout;
 use std::path::PathBuf;
@@ -23,8 +28,11 @@ use teng::util::planarvec2_experimental::ExponentialGrowingBounds;
 use teng::{Game, SetupInfo, SharedState, UpdateInfo, install_panic_handler, terminal_cleanup, terminal_setup, CustomBufWriter};
 
 mod balls_interpolator;
+mod leaderboard;
 mod multiplayer;
+mod prediction;
 mod slingshot;
+mod snapshots;
 mod world;
 
 #[derive(Parser, Debug)]
@@ -34,9 +42,24 @@ struct Args {
     #[clap(short, long, default_value = "http://localhost:3000")]
     server: String,
 
-    /// Use position interpolation
+    /// How to smooth ball positions between server updates. `--interpolate` alone means `on`.
+    #[clap(
+        short,
+        long,
+        value_enum,
+        default_value_t = InterpolationMode::Off,
+        num_args = 0..=1,
+        default_missing_value = "on"
+    )]
+    interpolate: InterpolationMode,
+
+    /// How far behind the server to render interpolated balls, in milliseconds
+    #[clap(long, default_value_t = 100)]
+    render_delay_ms: u64,
+
+    /// The nickname to play with.
     #[clap(short, long)]
-    interpolate: bool,
+    name: Option<String>,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -52,13 +75,21 @@ fn main() -> anyhow::Result<()> {
     let args = Args::parse();
 
     let (receive_rx, send_tx) = multiplayer::connect_to(args.server)?;
+    if let Some(name) = args.name {
+        send_tx.send(SendMessage::SetName(name))?;
+    }
 
     let mut game: Game<CustomBufWriter, GameState> = Game::new_with_custom_buf_writer();
     game.install_recommended_components();
     game.add_component(Box::new(GameComponent::new(receive_rx, send_tx)));
-    game.add_component(Box::new(BallsInterpolatorComponent::new(args.interpolate)));
+    game.add_component(Box::new(BallsInterpolatorComponent::new(
+        args.interpolate,
+        Duration::from_millis(args.render_delay_ms),
+    )));
+    game.add_component(Box::new(PredictionComponent::new()));
     game.add_component(Box::new(WorldComponent::new()));
     game.add_component(Box::new(SlingshotComponent::new()));
+    game.add_component(Box::new(LeaderboardComponent::new()));
     game.add_component(Box::new(DebugInfoComponent::new()));
     game.run()?;
 
@@ -67,23 +98,26 @@ fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
-#[derive(Debug)]
-struct OldAndNewBall {
-    old: Ball,
-    new: Ball,
-    // The time at which we should render the ball at `old`'s position. This is usually the time at which `new` was received.
-    timestamp_at_old: Instant,
-}
-
 #[derive(Debug, Default)]
 struct GameState {
     world: World,
-    raw_balls: HashMap<Identity, OldAndNewBall>,
-    balls: HashMap<Identity, Ball>,
+    // the balls as received from the server, interpolated into `balls`
+    raw_balls: HashMap<u64, BallSnapshots>,
+    server_clock: ServerClock,
+    balls: HashMap<u64, Ball>,
     foods: HashMap<u64, Food>,
+    viruses: HashMap<u64, Virus>,
+    leaderboard: HashMap<Identity, LeaderboardEntry>,
+    players: HashMap<Identity, Player>,
+    // only known once the server sent it
+    game_config: Option<GameConfig>,
     receive_rx: Option<Receiver<ReceiveMessage>>,
     send_tx: Option<Sender<SendMessage>>,
     our_identity: Option<Identity>,
+    // sequence number of the last input we sent, the server applies inputs with increasing numbers
+    input_seq: u64,
+    // inputs the server hasn't applied yet, oldest first
+    unacked_inputs: VecDeque<SentInput>,
 }
 
 impl GameState {
@@ -94,6 +128,18 @@ impl GameState {
     fn receiver(&self) -> &Receiver<ReceiveMessage> {
         self.receive_rx.as_ref().unwrap()
     }
+
+    fn send_impulse(&mut self, impulse_x: f64, impulse_y: f64) {
+        self.input_seq += 1;
+        let message = SendMessage::Impulse(self.input_seq, impulse_x, impulse_y);
+        self.sender().send(message).unwrap();
+        self.unacked_inputs.push_back(SentInput {
+            seq: self.input_seq,
+            impulse_x,
+            impulse_y,
+            sent_at: Instant::now(),
+        });
+    }
 }
 
 struct GameComponent {
@@ -118,29 +164,22 @@ impl GameComponent {
     fn apply_messages(&mut self, game_state: &mut GameState) {
         while let Ok(message) = game_state.receiver().try_recv() {
             match message {
-                ReceiveMessage::NewBall(ball) => {
-                    game_state.raw_balls.insert(
-                        ball.identity.clone(),
-                        OldAndNewBall {
-                            old: ball.clone(),
-                            new: ball,
-                            timestamp_at_old: Instant::now(),
-                        },
-                    );
-                }
-                ReceiveMessage::UpdateBall(old_ball, new_ball) => {
-                    game_state.raw_balls.insert(
-                        new_ball.identity.clone(),
-                        OldAndNewBall {
-                            old: old_ball,
-                            new: new_ball,
-                            timestamp_at_old: Instant::now(),
-                        },
-                    );
-                }
-                ReceiveMessage::DeleteBall(identity) => {
-                    game_state.raw_balls.remove(&identity);
-                    game_state.balls.remove(&identity);
+                ReceiveMessage::NewBall(ball, server_time)
+                | ReceiveMessage::UpdateBall(ball, server_time) => {
+                    // rows not changed by a reducer belong to the latest state we know of.
+                    // before the first tick, they are older than anything that follows.
+                    let timestamp = server_time
+                        .or(game_state.server_clock.latest_tick())
+                        .unwrap_or(Timestamp::from_micros_since_unix_epoch(0));
+                    game_state
+                        .raw_balls
+                        .entry(ball.id)
+                        .or_default()
+                        .push(timestamp, ball);
+                }
+                ReceiveMessage::DeleteBall(id) => {
+                    game_state.raw_balls.remove(&id);
+                    game_state.balls.remove(&id);
                 }
                 ReceiveMessage::OurIdentity(identity) => {
                     game_state.our_identity = Some(identity);
@@ -154,10 +193,48 @@ impl GameComponent {
                 ReceiveMessage::DeleteFood(id) => {
                     game_state.foods.remove(&id);
                 }
+                ReceiveMessage::NewVirus(virus) => {
+                    game_state.viruses.insert(virus.id, virus);
+                }
+                ReceiveMessage::UpdateVirus(virus) => {
+                    game_state.viruses.insert(virus.id, virus);
+                }
+                ReceiveMessage::DeleteVirus(id) => {
+                    game_state.viruses.remove(&id);
+                }
+                ReceiveMessage::NewLeaderboardEntry(entry) => {
+                    game_state.leaderboard.insert(entry.owner, entry);
+                }
+                ReceiveMessage::UpdateLeaderboardEntry(entry) => {
+                    game_state.leaderboard.insert(entry.owner, entry);
+                }
+                ReceiveMessage::DeleteLeaderboardEntry(owner) => {
+                    game_state.leaderboard.remove(&owner);
+                }
+                ReceiveMessage::NewGameConfig(config) => {
+                    game_state.game_config = Some(config);
+                }
+                ReceiveMessage::UpdateGameConfig(config) => {
+                    game_state.game_config = Some(config);
+                }
+                ReceiveMessage::NewPlayer(player) => {
+                    game_state.players.insert(player.identity, player);
+                }
+                ReceiveMessage::UpdatePlayer(player) => {
+                    game_state.players.insert(player.identity, player);
+                }
+                ReceiveMessage::DeletePlayer(identity) => {
+                    game_state.players.remove(&identity);
+                }
                 ReceiveMessage::NewPhysicsTick(t) => {
                     let duration = t.duration_since(self.last_tick);
                     self.last_frametime = duration.unwrap_or_default();
                     self.last_tick = t;
+                    game_state.server_clock.observe_tick(t, Instant::now());
+                    // balls changed in this tick replace these with their new state
+                    for snapshots in game_state.raw_balls.values_mut() {
+                        snapshots.unchanged_until(t);
+                    }
                 }
             }
         }
@@ -189,8 +266,9 @@ impl Component<GameState> for GameComponent {
             format!("frametime: {:.2}ms", self.last_frametime.as_millis()),
         );
 
-        // listen to keyboard events to apply impulses
-        let impulse_strength = 10.0;
+        // listen to keyboard events to apply impulses.
+        // impulses are velocity changes in world units per second
+        let impulse_strength = 600.0;
         let mut impulse = (0.0, 0.0);
         if shared_state.pressed_keys.did_press_char_ignore_case('w')
             || shared_state.pressed_keys.did_press(KeyCode::Up)
@@ -213,11 +291,6 @@ impl Component<GameState> for GameComponent {
             impulse.0 += impulse_strength;
         }
 
-        if impulse != (0.0, 0.0) {
-            let message = SendMessage::Impulse(impulse.0, impulse.1);
-            shared_state.custom.sender().send(message).unwrap();
-        }
-
         // use mouse look dir to apply impulse
         let (x, y) = shared_state.mouse_info.last_mouse_pos;
         let x = x as i64;
@@ -232,7 +305,24 @@ impl Component<GameState> for GameComponent {
         // if shared_state.pressed_keys.did_press_char(' ') {
         // if shared_state.mouse_info.right_mouse_down {
         if shared_state.mouse_pressed.right {
-            let message = SendMessage::Impulse(diff_x as f64, diff_y as f64);
+            impulse.0 += diff_x as f64 * 60.0;
+            impulse.1 += diff_y as f64 * 60.0;
+        }
+
+        // the server only applies our latest input each tick, so send one combined impulse per frame
+        if impulse != (0.0, 0.0) {
+            shared_state.custom.send_impulse(impulse.0, impulse.1);
+        }
+
+        // split our cells towards the mouse
+        if shared_state.pressed_keys.did_press_char(' ') {
+            let message = SendMessage::Split(diff_x as f64, diff_y as f64);
+            shared_state.custom.sender().send(message).unwrap();
+        }
+
+        // eject mass towards the mouse
+        if shared_state.pressed_keys.did_press_char_ignore_case('e') {
+            let message = SendMessage::EjectMass(diff_x as f64, diff_y as f64);
             shared_state.custom.sender().send(message).unwrap();
         }
     }
@@ -10,22 +10,105 @@ use spacetimedb_sdk::{
 
 pub use module_bindings::Ball;
 pub use module_bindings::Food;
+pub use module_bindings::GameConfig;
+pub use module_bindings::LeaderboardEntry;
+pub use module_bindings::Player;
+pub use module_bindings::Virus;
+
+impl From<&GameConfig> for tagars_physics::Config {
+    fn from(config: &GameConfig) -> Self {
+        tagars_physics::Config {
+            world_border_min_x: config.world_border_min_x,
+            world_border_max_x: config.world_border_max_x,
+            world_border_min_y: config.world_border_min_y,
+            world_border_max_y: config.world_border_max_y,
+            default_radius: config.default_radius,
+            delta_radius_required_for_eating: config.delta_radius_required_for_eating,
+            drag_per_second: config.drag_per_second,
+            restitution: config.restitution,
+            max_impulse: config.max_impulse,
+            broadphase: match config.broadphase {
+                BroadphaseKind::SpatialHash => tagars_physics::BroadphaseKind::SpatialHash,
+                BroadphaseKind::SweepAndPrune => tagars_physics::BroadphaseKind::SweepAndPrune,
+            },
+        }
+    }
+}
+
+impl From<&Ball> for tagars_physics::Ball<Identity> {
+    fn from(ball: &Ball) -> Self {
+        tagars_physics::Ball {
+            id: ball.id,
+            owner: ball.owner,
+            x: ball.x,
+            y: ball.y,
+            vx: ball.vx,
+            vy: ball.vy,
+            radius: ball.radius,
+            dead: ball.dead,
+            merge_at_micros: ball.merge_at.to_micros_since_unix_epoch(),
+        }
+    }
+}
+
+impl From<&Food> for tagars_physics::Food<Identity> {
+    fn from(food: &Food) -> Self {
+        tagars_physics::Food {
+            id: food.id,
+            x: food.x,
+            y: food.y,
+            vx: food.vx,
+            vy: food.vy,
+            mass: food.mass,
+            owner: food.owner,
+        }
+    }
+}
+
+impl From<&Virus> for tagars_physics::Virus {
+    fn from(virus: &Virus) -> Self {
+        tagars_physics::Virus {
+            id: virus.id,
+            x: virus.x,
+            y: virus.y,
+            radius: virus.radius,
+            fed: virus.fed,
+        }
+    }
+}
 
 #[derive(Debug)]
 pub enum ReceiveMessage {
-    NewBall(Ball),
-    // old and new
-    UpdateBall(Ball, Ball),
-    DeleteBall(Identity),
+    // the ball and the server time of its state, if known
+    NewBall(Ball, Option<Timestamp>),
+    UpdateBall(Ball, Option<Timestamp>),
+    DeleteBall(u64),
     OurIdentity(Identity),
     NewFood(Food),
     UpdateFood(Food),
     DeleteFood(u64),
+    NewVirus(Virus),
+    UpdateVirus(Virus),
+    DeleteVirus(u64),
+    NewLeaderboardEntry(LeaderboardEntry),
+    UpdateLeaderboardEntry(LeaderboardEntry),
+    DeleteLeaderboardEntry(Identity),
+    NewGameConfig(GameConfig),
+    UpdateGameConfig(GameConfig),
+    NewPlayer(Player),
+    UpdatePlayer(Player),
+    DeletePlayer(Identity),
     NewPhysicsTick(Timestamp),
 }
 
 pub enum SendMessage {
-    Impulse(f64, f64),
+    // input sequence number, impulse
+    Impulse(u64, f64, f64),
+    // aim direction
+    Split(f64, f64),
+    // aim direction
+    EjectMass(f64, f64),
+    SetName(String),
 }
 
 /// Connect to the server at the given URL.
@@ -68,7 +151,10 @@ fn multiplayer_loop(
     // Handle input
     loop {
         match send_rx.recv() {
-            Ok(SendMessage::Impulse(x, y)) => ctx.reducers.apply_impulse(x, y).unwrap(),
+            Ok(SendMessage::Impulse(seq, x, y)) => ctx.reducers.apply_impulse(seq, x, y).unwrap(),
+            Ok(SendMessage::Split(x, y)) => ctx.reducers.split(x, y).unwrap(),
+            Ok(SendMessage::EjectMass(x, y)) => ctx.reducers.eject_mass(x, y).unwrap(),
+            Ok(SendMessage::SetName(name)) => ctx.reducers.set_name(name).unwrap(),
             Err(_) => break,
         }
     }
@@ -105,7 +191,11 @@ fn register_callbacks(ctx: &DbConnection, tx: Sender<ReceiveMessage>) {
     {
         let tx = tx.clone();
         ctx.db.balls().on_insert(move |ctx, ball| {
-            tx.send(ReceiveMessage::NewBall(ball.clone())).unwrap();
+            tx.send(ReceiveMessage::NewBall(
+                ball.clone(),
+                server_time_of(&ctx.event),
+            ))
+            .unwrap();
         });
     }
 
@@ -113,8 +203,8 @@ fn register_callbacks(ctx: &DbConnection, tx: Sender<ReceiveMessage>) {
         let tx = tx.clone();
         ctx.db.balls().on_update(move |ctx, old_ball, new_ball| {
             tx.send(ReceiveMessage::UpdateBall(
-                old_ball.clone(),
                 new_ball.clone(),
+                server_time_of(&ctx.event),
             ))
             .unwrap();
         });
@@ -123,7 +213,7 @@ fn register_callbacks(ctx: &DbConnection, tx: Sender<ReceiveMessage>) {
     {
         let tx = tx.clone();
         ctx.db.balls().on_delete(move |ctx, ball| {
-            tx.send(ReceiveMessage::DeleteBall(ball.identity)).unwrap();
+            tx.send(ReceiveMessage::DeleteBall(ball.id)).unwrap();
         });
     }
 
@@ -150,6 +240,103 @@ fn register_callbacks(ctx: &DbConnection, tx: Sender<ReceiveMessage>) {
         });
     }
 
+    // viruses
+    {
+        let tx = tx.clone();
+        ctx.db.viruses().on_insert(move |ctx, virus| {
+            tx.send(ReceiveMessage::NewVirus(virus.clone())).unwrap();
+        });
+    }
+
+    {
+        let tx = tx.clone();
+        ctx.db
+            .viruses()
+            .on_update(move |ctx, old_virus, new_virus| {
+                tx.send(ReceiveMessage::UpdateVirus(new_virus.clone()))
+                    .unwrap();
+            });
+    }
+
+    {
+        let tx = tx.clone();
+        ctx.db.viruses().on_delete(move |ctx, virus| {
+            tx.send(ReceiveMessage::DeleteVirus(virus.id)).unwrap();
+        });
+    }
+
+    // leaderboard
+    {
+        let tx = tx.clone();
+        ctx.db.leaderboard().on_insert(move |ctx, entry| {
+            tx.send(ReceiveMessage::NewLeaderboardEntry(entry.clone()))
+                .unwrap();
+        });
+    }
+
+    {
+        let tx = tx.clone();
+        ctx.db
+            .leaderboard()
+            .on_update(move |ctx, old_entry, new_entry| {
+                tx.send(ReceiveMessage::UpdateLeaderboardEntry(new_entry.clone()))
+                    .unwrap();
+            });
+    }
+
+    {
+        let tx = tx.clone();
+        ctx.db.leaderboard().on_delete(move |ctx, entry| {
+            tx.send(ReceiveMessage::DeleteLeaderboardEntry(entry.owner))
+                .unwrap();
+        });
+    }
+
+    // game config
+    {
+        let tx = tx.clone();
+        ctx.db.game_config().on_insert(move |ctx, config| {
+            tx.send(ReceiveMessage::NewGameConfig(config.clone()))
+                .unwrap();
+        });
+    }
+
+    {
+        let tx = tx.clone();
+        ctx.db
+            .game_config()
+            .on_update(move |ctx, old_config, new_config| {
+                tx.send(ReceiveMessage::UpdateGameConfig(new_config.clone()))
+                    .unwrap();
+            });
+    }
+
+    // players
+    {
+        let tx = tx.clone();
+        ctx.db.players().on_insert(move |ctx, player| {
+            tx.send(ReceiveMessage::NewPlayer(player.clone())).unwrap();
+        });
+    }
+
+    {
+        let tx = tx.clone();
+        ctx.db
+            .players()
+            .on_update(move |ctx, old_player, new_player| {
+                tx.send(ReceiveMessage::UpdatePlayer(new_player.clone()))
+                    .unwrap();
+            });
+    }
+
+    {
+        let tx = tx.clone();
+        ctx.db.players().on_delete(move |ctx, player| {
+            tx.send(ReceiveMessage::DeletePlayer(player.identity))
+                .unwrap();
+        });
+    }
+
     // physics ticks inserts
     {
         let tx = tx.clone();
@@ -160,11 +347,30 @@ fn register_callbacks(ctx: &DbConnection, tx: Sender<ReceiveMessage>) {
     }
 }
 
+/// The server time at which the transaction behind `event` ran.
+/// For `update_balls` this is the `ticked_at` of the physics tick it inserted.
+fn server_time_of(event: &Event<Reducer>) -> Option<Timestamp> {
+    match event {
+        Event::Reducer(reducer_event) => Some(reducer_event.timestamp),
+        _ => None,
+    }
+}
+
 /// Register subscriptions for all rows of both tables.
 fn subscribe_to_tables(ctx: &DbConnection) {
+    let top_ranks = format!(
+        "SELECT * FROM leaderboard WHERE rank <= {}",
+        tagars_physics::LEADERBOARD_TOP_N
+    );
     ctx.subscription_builder().subscribe([
         "SELECT * FROM balls",
         "SELECT * FROM foods",
+        "SELECT * FROM viruses",
+        "SELECT * FROM players",
+        "SELECT * FROM game_config",
+        // the top ranks and our own rank
+        top_ranks.as_str(),
+        "SELECT * FROM leaderboard WHERE owner = :sender",
         "SELECT * FROM physics_ticks",
     ]);
 }
@@ -9,7 +9,8 @@ use super::rgb_type::Rgb;
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
 #[sats(crate = __lib)]
 pub struct Ball {
-    pub identity: __sdk::Identity,
+    pub id: u64,
+    pub owner: __sdk::Identity,
     pub x: f64,
     pub y: f64,
     pub vx: f64,
@@ -17,6 +18,7 @@ pub struct Ball {
     pub radius: f64,
     pub color: Rgb,
     pub dead: bool,
+    pub merge_at: __sdk::Timestamp,
 }
 
 impl __sdk::InModule for Ball {
@@ -82,7 +82,7 @@ impl<'ctx> __sdk::Table for BallsTableHandle<'ctx> {
 #[doc(hidden)]
 pub(super) fn register_table(client_cache: &mut __sdk::ClientCache<super::RemoteModule>) {
     let _table = client_cache.get_or_make_table::<Ball>("balls");
-    _table.add_unique_constraint::<__sdk::Identity>("identity", |row| &row.identity);
+    _table.add_unique_constraint::<u64>("id", |row| &row.id);
 }
 pub struct BallsUpdateCallbackId(__sdk::CallbackId);
 
@@ -112,34 +112,32 @@ pub(super) fn parse_table_update(
     })
 }
 
-/// Access to the `identity` unique index on the table `balls`,
+/// Access to the `id` unique index on the table `balls`,
 /// which allows point queries on the field of the same name
-/// via the [`BallsIdentityUnique::find`] method.
+/// via the [`BallsIdUnique::find`] method.
 ///
 /// Users are encouraged not to explicitly reference this type,
 /// but to directly chain method calls,
-/// like `ctx.db.balls().identity().find(...)`.
-pub struct BallsIdentityUnique<'ctx> {
-    imp: __sdk::UniqueConstraintHandle<Ball, __sdk::Identity>,
+/// like `ctx.db.balls().id().find(...)`.
+pub struct BallsIdUnique<'ctx> {
+    imp: __sdk::UniqueConstraintHandle<Ball, u64>,
     phantom: std::marker::PhantomData<&'ctx super::RemoteTables>,
 }
 
 impl<'ctx> BallsTableHandle<'ctx> {
-    /// Get a handle on the `identity` unique index on the table `balls`.
-    pub fn identity(&self) -> BallsIdentityUnique<'ctx> {
-        BallsIdentityUnique {
-            imp: self
-                .imp
-                .get_unique_constraint::<__sdk::Identity>("identity"),
+    /// Get a handle on the `id` unique index on the table `balls`.
+    pub fn id(&self) -> BallsIdUnique<'ctx> {
+        BallsIdUnique {
+            imp: self.imp.get_unique_constraint::<u64>("id"),
             phantom: std::marker::PhantomData,
         }
     }
 }
 
-impl<'ctx> BallsIdentityUnique<'ctx> {
-    /// Find the subscribed row whose `identity` column value is equal to `col_val`,
+impl<'ctx> BallsIdUnique<'ctx> {
+    /// Find the subscribed row whose `id` column value is equal to `col_val`,
     /// if such a row is present in the client cache.
-    pub fn find(&self, col_val: &__sdk::Identity) -> Option<Ball> {
+    pub fn find(&self, col_val: &u64) -> Option<Ball> {
         self.imp.find(col_val)
     }
 }
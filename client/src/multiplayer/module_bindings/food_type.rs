@@ -12,7 +12,11 @@ pub struct Food {
     pub id: u64,
     pub x: f64,
     pub y: f64,
+    pub vx: f64,
+    pub vy: f64,
+    pub mass: f64,
     pub color: Rgb,
+    pub owner: Option<__sdk::Identity>,
 }
 
 impl __sdk::InModule for Food {
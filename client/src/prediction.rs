@@ -0,0 +1,140 @@
+use crate::GameState;
+use spacetimedb_sdk::Timestamp;
+use std::time::{Duration, Instant};
+use teng::components::Component;
+use teng::{SharedState, UpdateInfo};
+
+/// An impulse we sent to the server that it hasn't acknowledged yet.
+#[derive(Debug)]
+pub struct SentInput {
+    pub seq: u64,
+    pub impulse_x: f64,
+    pub impulse_y: f64,
+    pub sent_at: Instant,
+}
+
+/// Predicts our own cells by simulating them locally from the latest server state and the inputs
+/// the server hasn't applied yet, so they react to input without waiting for a round trip.
+/// Other balls are left to `BallsInterpolatorComponent`, which must run before this component.
+pub struct PredictionComponent;
+
+impl PredictionComponent {
+    // like the server, which never simulates more than this in one tick, so a stale state
+    // doesn't make us simulate for ages
+    const MAX_REPLAY: Duration = Duration::from_millis(250);
+
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+// `to - from` in micros, negative if `to` is earlier
+fn micros_between(from: Instant, to: Instant) -> i64 {
+    if to >= from {
+        (to - from).as_micros() as i64
+    } else {
+        -((from - to).as_micros() as i64)
+    }
+}
+
+impl Component<GameState> for PredictionComponent {
+    fn update(&mut self, update_info: UpdateInfo, shared_state: &mut SharedState<GameState>) {
+        let game_state = &mut shared_state.custom;
+        let Some(our_identity) = game_state.our_identity else {
+            return;
+        };
+        let Some(config) = &game_state.game_config else {
+            return;
+        };
+
+        // forget inputs the server has applied, their effect is part of the server state
+        let acked_seq = game_state
+            .players
+            .get(&our_identity)
+            .map_or(0, |player| player.last_input_seq);
+        while game_state
+            .unacked_inputs
+            .front()
+            .is_some_and(|input| input.seq <= acked_seq)
+        {
+            game_state.unacked_inputs.pop_front();
+        }
+
+        // start from the latest server state of our cells
+        let mut balls = Vec::new();
+        let mut state_time = None::<Timestamp>;
+        for snapshots in game_state.raw_balls.values() {
+            let Some((timestamp, ball)) = snapshots.latest() else {
+                continue;
+            };
+            if ball.owner != our_identity || ball.dead {
+                continue;
+            }
+            balls.push(tagars_physics::Ball::from(ball));
+            state_time = state_time.max(Some(*timestamp));
+        }
+        // the local time at which we received that state
+        let Some(mut simulated_until) =
+            state_time.and_then(|timestamp| game_state.server_clock.local_instant_of(timestamp))
+        else {
+            return;
+        };
+
+        // The server applies only the latest input of a player per tick, so do the same with the
+        // inputs sent within one tick length. Ticks are counted from the tick of our state.
+        let tick_micros = config.tick_micros.max(1);
+        let tick_of = |input: &SentInput| {
+            micros_between(simulated_until, input.sent_at).div_euclid(tick_micros)
+        };
+        let mut inputs = Vec::<&SentInput>::new();
+        for input in &game_state.unacked_inputs {
+            // a later input of the same tick replaces the earlier one
+            if inputs
+                .last()
+                .is_some_and(|&last| tick_of(last) == tick_of(input))
+            {
+                inputs.pop();
+            }
+            inputs.push(input);
+        }
+
+        let now = update_info.current_time;
+        if let Some(replay_from) = now.checked_sub(Self::MAX_REPLAY) {
+            simulated_until = simulated_until.max(replay_from);
+        }
+        // the world runs on the server clock, which decides when split cells merge
+        let Some(start_micros) = game_state.server_clock.server_micros_at(simulated_until) else {
+            return;
+        };
+        let mut world =
+            tagars_physics::World::new(tagars_physics::Config::from(config), start_micros);
+        world.balls = balls;
+
+        // replay the unacknowledged inputs in the order we sent them, then simulate up to now
+        let mut rng = tagars_physics::SplitMix64(0);
+        for input in inputs {
+            let applied_at = input.sent_at.max(simulated_until);
+            let dt = (applied_at - simulated_until).as_secs_f64();
+            tagars_physics::step(&mut world, &[], dt, &mut rng);
+            let input = tagars_physics::Input {
+                owner: our_identity,
+                impulse_x: input.impulse_x,
+                impulse_y: input.impulse_y,
+            };
+            tagars_physics::step(&mut world, &[input], 0.0, &mut rng);
+            simulated_until = applied_at;
+        }
+        let dt = (now.max(simulated_until) - simulated_until).as_secs_f64();
+        tagars_physics::step(&mut world, &[], dt, &mut rng);
+
+        // only take over the motion, everything else stays authoritative
+        for predicted in world.balls {
+            if let Some(ball) = game_state.balls.get_mut(&predicted.id) {
+                ball.x = predicted.x;
+                ball.y = predicted.y;
+                ball.vx = predicted.vx;
+                ball.vy = predicted.vy;
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{GameState, multiplayer};
+use crate::GameState;
 use crossterm::event::{Event, MouseButton, MouseEventKind};
 use teng::components::Component;
 use teng::rendering::color::Color;
@@ -96,14 +96,12 @@ impl Component<GameState> for SlingshotComponent {
         self.slingshot = slingshot;
 
         if let Some((s_x, s_y)) = self.slingshot {
-            const AMPLIFIER: f64 = 0.1;
+            // impulses are in velocity per second, like the keyboard and mouse inputs
+            const AMPLIFIER: f64 = 6.0;
             let impulse_x = s_x as f64 * AMPLIFIER;
             let impulse_y = s_y as f64 * AMPLIFIER * 2.0;
 
-            game_state
-                .sender()
-                .send(multiplayer::SendMessage::Impulse(impulse_x, impulse_y))
-                .unwrap();
+            game_state.send_impulse(impulse_x, impulse_y);
 
             self.first_down = None;
             self.last_release = None;
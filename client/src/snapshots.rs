@@ -0,0 +1,241 @@
+use crate::multiplayer::Ball;
+use spacetimedb_sdk::Timestamp;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Maps server timestamps to local time.
+///
+/// The server clock and ours differ, so we estimate the offset between them from the physics ticks
+/// we receive. The offset includes the network delay, so a server timestamp maps to roughly the
+/// time at which we received the state of that timestamp.
+#[derive(Debug)]
+pub struct ServerClock {
+    // local times are measured in micros since this instant
+    epoch: Instant,
+    // local micros minus server micros
+    offset_micros: Option<i64>,
+    latest_tick: Option<Timestamp>,
+}
+
+impl Default for ServerClock {
+    fn default() -> Self {
+        Self {
+            epoch: Instant::now(),
+            offset_micros: None,
+            latest_tick: None,
+        }
+    }
+}
+
+impl ServerClock {
+    /// Feeds the clock with a physics tick of the server and the time we received it at.
+    pub fn observe_tick(&mut self, ticked_at: Timestamp, received_at: Instant) {
+        let sample = self.local_micros(received_at) - ticked_at.to_micros_since_unix_epoch();
+        // the fastest delivered tick is the best estimate, so follow lower samples immediately.
+        // higher ones are either jitter or a real change in delay, so only follow them slowly.
+        self.offset_micros = Some(match self.offset_micros {
+            Some(offset) if sample > offset => offset + (sample - offset) / 100,
+            _ => sample,
+        });
+        self.latest_tick = self.latest_tick.max(Some(ticked_at));
+    }
+
+    /// The latest physics tick we know of.
+    pub fn latest_tick(&self) -> Option<Timestamp> {
+        self.latest_tick
+    }
+
+    /// The server time corresponding to the local time `at`.
+    pub fn server_micros_at(&self, at: Instant) -> Option<i64> {
+        Some(self.local_micros(at) - self.offset_micros?)
+    }
+
+    /// The local time corresponding to the server time `timestamp`.
+    pub fn local_instant_of(&self, timestamp: Timestamp) -> Option<Instant> {
+        let local_micros = timestamp.to_micros_since_unix_epoch() + self.offset_micros?;
+        if local_micros >= 0 {
+            self.epoch
+                .checked_add(Duration::from_micros(local_micros as u64))
+        } else {
+            self.epoch
+                .checked_sub(Duration::from_micros(-local_micros as u64))
+        }
+    }
+
+    fn local_micros(&self, at: Instant) -> i64 {
+        if at >= self.epoch {
+            (at - self.epoch).as_micros() as i64
+        } else {
+            -((self.epoch - at).as_micros() as i64)
+        }
+    }
+}
+
+/// The recent states of one ball, oldest first, each with the server time it belongs to.
+#[derive(Debug, Default)]
+pub struct BallSnapshots {
+    snapshots: VecDeque<(Timestamp, Ball)>,
+    // where `extrapolate_at` put the ball last time
+    rendered: Option<Rendered>,
+    // offset from the sampled to the rendered position, decays over time
+    correction: (f64, f64),
+}
+
+#[derive(Debug)]
+struct Rendered {
+    latest: Timestamp,
+    extrapolated: bool,
+    x: f64,
+    y: f64,
+}
+
+impl BallSnapshots {
+    // bounds the buffer if we don't render for a while
+    const MAX_SNAPSHOTS: usize = 64;
+    // don't guess for longer than this, a stalled ball is better than one flying off
+    const MAX_EXTRAPOLATION: Duration = Duration::from_millis(250);
+    // half of the error of an extrapolation is blended out within this time
+    const BLEND_HALF_LIFE: Duration = Duration::from_millis(50);
+
+    pub fn push(&mut self, timestamp: Timestamp, ball: Ball) {
+        // the server might report a state for a tick we already have, the later one wins
+        while self
+            .snapshots
+            .back()
+            .is_some_and(|(latest, _)| *latest >= timestamp)
+        {
+            self.snapshots.pop_back();
+        }
+        self.snapshots.push_back((timestamp, ball));
+        if self.snapshots.len() > Self::MAX_SNAPSHOTS {
+            self.snapshots.pop_front();
+        }
+    }
+
+    /// Records that the ball didn't change up to the physics tick `ticked_at`. The server only
+    /// writes balls that changed, so the state of a resting ball has to be carried forward to
+    /// every tick, otherwise it would be interpolated across the whole time it was resting.
+    pub fn unchanged_until(&mut self, ticked_at: Timestamp) {
+        let Some((latest, ball)) = self.snapshots.back() else {
+            return;
+        };
+        if *latest < ticked_at {
+            let ball = ball.clone();
+            self.push(ticked_at, ball);
+        }
+    }
+
+    /// The most recent state and its server time.
+    pub fn latest(&self) -> Option<&(Timestamp, Ball)> {
+        self.snapshots.back()
+    }
+
+    /// Forgets all snapshots that are not needed anymore to interpolate at `server_micros` or later.
+    pub fn prune_before(&mut self, server_micros: i64) {
+        while self
+            .snapshots
+            .get(1)
+            .is_some_and(|(timestamp, _)| timestamp.to_micros_since_unix_epoch() <= server_micros)
+        {
+            self.snapshots.pop_front();
+        }
+    }
+
+    /// The state of the ball at `server_micros`, interpolated between the surrounding snapshots.
+    /// Outside the buffered range, the closest snapshot is returned.
+    pub fn interpolate_at(&self, server_micros: i64) -> Option<Ball> {
+        let after = self.snapshots.partition_point(|(timestamp, _)| {
+            timestamp.to_micros_since_unix_epoch() <= server_micros
+        });
+        let (old_timestamp, old) = match after.checked_sub(1) {
+            Some(before) => &self.snapshots[before],
+            None => return self.snapshots.front().map(|(_, ball)| ball.clone()),
+        };
+        let Some((new_timestamp, new)) = self.snapshots.get(after) else {
+            return Some(old.clone());
+        };
+
+        let old_micros = old_timestamp.to_micros_since_unix_epoch();
+        let new_micros = new_timestamp.to_micros_since_unix_epoch();
+        let fraction = (server_micros - old_micros) as f64 / (new_micros - old_micros) as f64;
+        let lerp = |old: f64, new: f64| old + fraction * (new - old);
+
+        let ball = match (old.dead, new.dead) {
+            (false, false) => Ball {
+                x: lerp(old.x, new.x),
+                y: lerp(old.y, new.y),
+                vx: lerp(old.vx, new.vx),
+                vy: lerp(old.vy, new.vy),
+                radius: lerp(old.radius, new.radius),
+                ..new.clone()
+            },
+            // shrink away where it died
+            (false, true) => Ball {
+                radius: lerp(old.radius, 0.0),
+                ..old.clone()
+            },
+            // grow where it respawned, instead of flying across the world
+            (true, false) => Ball {
+                radius: lerp(0.0, new.radius),
+                ..new.clone()
+            },
+            (true, true) => new.clone(),
+        };
+        Some(ball)
+    }
+
+    /// Like `interpolate_at`, but past the latest snapshot the ball keeps moving like the server
+    /// would move it, for at most `MAX_EXTRAPOLATION`. Once the next snapshot arrives, the error of
+    /// the extrapolation is blended out over time instead of snapping the ball to its real position.
+    pub fn extrapolate_at(
+        &mut self,
+        server_micros: i64,
+        config: &tagars_physics::Config,
+        frame_time: Duration,
+    ) -> Option<Ball> {
+        let (latest_timestamp, latest) = self.latest()?;
+        let latest_timestamp = *latest_timestamp;
+        let ahead_micros = server_micros - latest_timestamp.to_micros_since_unix_epoch();
+        let extrapolated = ahead_micros > 0 && !latest.dead;
+        let mut ball = if extrapolated {
+            let ahead = Duration::from_micros(ahead_micros as u64).min(Self::MAX_EXTRAPOLATION);
+            let mut world = tagars_physics::World::new(
+                config.clone(),
+                latest_timestamp.to_micros_since_unix_epoch(),
+            );
+            world.balls.push(tagars_physics::Ball::from(latest));
+            let mut rng = tagars_physics::SplitMix64(0);
+            tagars_physics::step(&mut world, &[], ahead.as_secs_f64(), &mut rng);
+            let moved = &world.balls[0];
+            Ball {
+                x: moved.x,
+                y: moved.y,
+                vx: moved.vx,
+                vy: moved.vy,
+                ..latest.clone()
+            }
+        } else {
+            self.interpolate_at(server_micros)?
+        };
+
+        if let Some(rendered) = &self.rendered {
+            // a new snapshot replaced what we guessed, continue from where we rendered the ball
+            if rendered.extrapolated && rendered.latest != latest_timestamp {
+                self.correction = (rendered.x - ball.x, rendered.y - ball.y);
+            }
+        }
+        let decay = 0.5f64.powf(frame_time.as_secs_f64() / Self::BLEND_HALF_LIFE.as_secs_f64());
+        self.correction.0 *= decay;
+        self.correction.1 *= decay;
+        ball.x += self.correction.0;
+        ball.y += self.correction.1;
+
+        self.rendered = Some(Rendered {
+            latest: latest_timestamp,
+            extrapolated,
+            x: ball.x,
+            y: ball.y,
+        });
+        Some(ball)
+    }
+}
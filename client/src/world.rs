@@ -20,11 +20,6 @@ pub struct World {
 }
 
 impl World {
-    const WORLD_BORDER_MIN_X: i64 = -200;
-    const WORLD_BORDER_MAX_X: i64 = 200;
-    const WORLD_BORDER_MIN_Y: i64 = -200;
-    const WORLD_BORDER_MAX_Y: i64 = 200;
-
     pub fn to_screen_pos(&self, world_x: i64, world_y: i64) -> (i64, i64) {
         let camera_x = self.camera_attach.0;
         let camera_y = self.camera_attach.1;
@@ -66,6 +61,64 @@ impl WorldComponent {
             checkerboard_display: HalfBlockDisplayRender::new(0, 0),
         }
     }
+
+    /// Draws a filled circle centered at the given screen position.
+    fn draw_disc(&mut self, screen_x: i64, screen_y: i64, radius: i64, color: Color) {
+        for_coord_in_line(
+            false,
+            (screen_x - radius, 0),
+            (screen_x + radius, 0),
+            |x, _| {
+                for_coord_in_line(
+                    false,
+                    (0, screen_y - radius),
+                    (0, screen_y + radius),
+                    |_, y| {
+                        if (x - screen_x).pow(2) + (y - screen_y).pow(2) < radius.pow(2) {
+                            if x < 0 || y < 0 {
+                                return;
+                            }
+                            self.display.set_color(x as usize, y as usize, color);
+                        }
+                    },
+                );
+            },
+        );
+    }
+
+    /// Draws a virus centered at the given screen position: a green disc with a ring of spikes.
+    fn draw_virus(&mut self, screen_x: i64, screen_y: i64, radius: i64) {
+        const SPIKES: f64 = 16.0;
+        const SPIKE_LENGTH: i64 = 2;
+        let body_color = Color::Rgb([30, 160, 30]);
+        let spike_color = Color::Rgb([80, 230, 80]);
+
+        let outer_radius = radius + SPIKE_LENGTH;
+        for y in screen_y - outer_radius..=screen_y + outer_radius {
+            for x in screen_x - outer_radius..=screen_x + outer_radius {
+                if x < 0 || y < 0 {
+                    continue;
+                }
+                let dx = (x - screen_x) as f64;
+                let dy = (y - screen_y) as f64;
+                let distance = (dx * dx + dy * dy).sqrt();
+                if distance < radius as f64 {
+                    self.display.set_color(x as usize, y as usize, body_color);
+                    continue;
+                }
+                // spikes taper off the further out we go
+                let spike_progress = (distance - radius as f64) / SPIKE_LENGTH as f64;
+                if spike_progress >= 1.0 {
+                    continue;
+                }
+                let angle = dy.atan2(dx) / std::f64::consts::TAU * SPIKES;
+                let distance_to_spike_center = (angle.rem_euclid(1.0) - 0.5).abs();
+                if distance_to_spike_center < 0.5 * (1.0 - spike_progress) {
+                    self.display.set_color(x as usize, y as usize, spike_color);
+                }
+            }
+        }
+    }
 }
 
 impl Component<GameState> for WorldComponent {
@@ -90,14 +143,26 @@ impl Component<GameState> for WorldComponent {
     }
 
     fn update(&mut self, update_info: UpdateInfo, shared_state: &mut SharedState<GameState>) {
-        let our_ball = shared_state
-            .custom
-            .our_identity
-            .as_ref()
-            .and_then(|identity| shared_state.custom.balls.get(identity));
-        if let Some(ball) = our_ball {
-            shared_state.custom.world.camera_attach =
-                (ball.x.floor() as i64, ball.y.floor() as i64);
+        // center the camera on the (mass-weighted) centroid of our cells
+        if let Some(identity) = shared_state.custom.our_identity.as_ref() {
+            let mut total_mass = 0.0;
+            let mut centroid_x = 0.0;
+            let mut centroid_y = 0.0;
+            for ball in shared_state.custom.balls.values() {
+                if ball.owner != *identity || ball.dead {
+                    continue;
+                }
+                let mass = tagars_physics::mass_of_radius(ball.radius);
+                total_mass += mass;
+                centroid_x += ball.x * mass;
+                centroid_y += ball.y * mass;
+            }
+            if total_mass > 0.0 {
+                centroid_x /= total_mass;
+                centroid_y /= total_mass;
+                shared_state.custom.world.camera_attach =
+                    (centroid_x.floor() as i64, centroid_y.floor() as i64);
+            }
         }
 
         // render to half block display
@@ -107,15 +172,22 @@ impl Component<GameState> for WorldComponent {
         let checkerboard_width = 40;
         let checkerboard_color_a = Color::Rgb([50, 50, 50]);
         let checkerboard_color_b = Color::Rgb([100, 100, 100]);
+        // the world borders are unknown until the game config arrived
+        let (border_min_x, border_max_x, border_min_y, border_max_y) =
+            match &shared_state.custom.game_config {
+                Some(config) => (
+                    config.world_border_min_x.floor() as i64,
+                    config.world_border_max_x.floor() as i64,
+                    config.world_border_min_y.floor() as i64,
+                    config.world_border_max_y.floor() as i64,
+                ),
+                None => (0, 0, 0, 0),
+            };
         for sx in 0..shared_state.custom.world.screen_width {
             for sy in 0..shared_state.custom.world.screen_height {
                 let (x, y) = (sx as i64, sy as i64);
                 let (x, y) = shared_state.custom.world.to_world_pos(x, y);
-                if x < World::WORLD_BORDER_MIN_X
-                    || x >= World::WORLD_BORDER_MAX_X
-                    || y < World::WORLD_BORDER_MIN_Y
-                    || y >= World::WORLD_BORDER_MAX_Y
-                {
+                if x < border_min_x || x >= border_max_x || y < border_min_y || y >= border_max_y {
                     continue;
                 }
                 let scaled_x = (x as f64 / checkerboard_width as f64).floor() as i64;
@@ -136,41 +208,60 @@ impl Component<GameState> for WorldComponent {
                 .world
                 .to_screen_pos(food.x.floor() as i64, food.y.floor() as i64);
             let color = Color::Rgb([food.color.r, food.color.g, food.color.b]);
-            self.display
-                .set_color(screen_x as usize, screen_y as usize, color);
+            // regular foods are a single pixel, ejected pellets carry more mass and are drawn bigger
+            let radius = (food.mass / std::f64::consts::PI).sqrt();
+            if radius > 1.0 {
+                self.draw_disc(screen_x, screen_y, radius.round() as i64, color);
+            } else {
+                self.display
+                    .set_color(screen_x as usize, screen_y as usize, color);
+            }
         }
 
-        for ball in shared_state.custom.balls.values() {
-            if ball.dead {
-                continue;
-            }
+        // balls too small to be popped by a virus pass under it, the others are drawn on top
+        let mut balls = shared_state
+            .custom
+            .balls
+            .values()
+            .filter(|ball| !ball.dead)
+            .collect::<Vec<_>>();
+        balls.sort_by(|a, b| a.radius.total_cmp(&b.radius));
+        let virus_radius = shared_state
+            .custom
+            .viruses
+            .values()
+            .map(|virus| virus.radius)
+            .fold(0.0, f64::max);
+        let first_big_ball = balls.partition_point(|ball| {
+            ball.radius < virus_radius * tagars_physics::Virus::POP_RADIUS_RATIO
+        });
+
+        for ball in &balls[..first_big_ball] {
             let (screen_x, screen_y) = shared_state
                 .custom
                 .world
                 .to_screen_pos(ball.x.floor() as i64, ball.y.floor() as i64);
             let radius = ball.radius as i64 + 1;
-            for_coord_in_line(
-                false,
-                (screen_x - radius, 0),
-                (screen_x + radius, 0),
-                |x, _| {
-                    for_coord_in_line(
-                        false,
-                        (0, screen_y - radius),
-                        (0, screen_y + radius),
-                        |_, y| {
-                            if (x - screen_x).pow(2) + (y - screen_y).pow(2) < radius.pow(2) {
-                                if x < 0 || y < 0 {
-                                    return;
-                                }
-                                let rgb = [ball.color.r, ball.color.g, ball.color.b];
-                                self.display
-                                    .set_color(x as usize, y as usize, Color::Rgb(rgb));
-                            }
-                        },
-                    );
-                },
-            );
+            let rgb = [ball.color.r, ball.color.g, ball.color.b];
+            self.draw_disc(screen_x, screen_y, radius, Color::Rgb(rgb));
+        }
+
+        for virus in shared_state.custom.viruses.values() {
+            let (screen_x, screen_y) = shared_state
+                .custom
+                .world
+                .to_screen_pos(virus.x.floor() as i64, virus.y.floor() as i64);
+            self.draw_virus(screen_x, screen_y, virus.radius as i64);
+        }
+
+        for ball in &balls[first_big_ball..] {
+            let (screen_x, screen_y) = shared_state
+                .custom
+                .world
+                .to_screen_pos(ball.x.floor() as i64, ball.y.floor() as i64);
+            let radius = ball.radius as i64 + 1;
+            let rgb = [ball.color.r, ball.color.g, ball.color.b];
+            self.draw_disc(screen_x, screen_y, radius, Color::Rgb(rgb));
         }
     }
 
@@ -189,6 +280,28 @@ impl Component<GameState> for WorldComponent {
 
         let world = &shared_state.custom.world;
 
+        // render name labels over balls
+        for ball in shared_state.custom.balls.values() {
+            if ball.dead {
+                continue;
+            }
+            let Some(player) = shared_state.custom.players.get(&ball.owner) else {
+                continue;
+            };
+            let (screen_x, screen_y) =
+                world.to_screen_pos(ball.x.floor() as i64, ball.y.floor() as i64);
+            let name_width = player.name.chars().count() as i64;
+            let x = screen_x - name_width / 2;
+            // world screen coordinates are in half blocks
+            let y = screen_y / 2;
+            if x < 0 || y < 0 {
+                continue;
+            }
+            player
+                .name
+                .render(renderer, x as usize, y as usize, coord_depth);
+        }
+
         // render world coords
         for y in 0..world.screen_height {
             let world_y = world.to_world_pos(0, y as i64).1;
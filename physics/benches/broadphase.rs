@@ -0,0 +1,58 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use std::hint::black_box;
+use tagars_physics::synthetic::{Masses, WorldSpec};
+use tagars_physics::{AnyBroadphase, Ball, Broadphase, BroadphaseKind, SpatialHashable};
+
+fn balls(masses: Masses) -> Vec<Ball<u64>> {
+    WorldSpec {
+        masses,
+        seed: 7,
+        ..WorldSpec::default()
+    }
+    .build()
+    .balls
+}
+
+fn count_pairs(broadphase: &mut AnyBroadphase<u64>, balls: &[Ball<u64>]) -> usize {
+    for ball in balls {
+        broadphase.set(ball.id, ball.get_aabb());
+    }
+    let mut pairs = 0;
+    broadphase.for_each_candidate_pair(&mut |_, _| pairs += 1);
+    pairs
+}
+
+fn bench_broadphases(c: &mut Criterion) {
+    for masses in Masses::ALL {
+        let balls = balls(masses);
+        // the balls moved a bit since the last tick
+        let moved = balls
+            .iter()
+            .map(|ball| Ball {
+                x: ball.x + 1.5,
+                y: ball.y - 0.5,
+                ..ball.clone()
+            })
+            .collect::<Vec<_>>();
+
+        let mut group = c.benchmark_group(format!("broadphase {masses:?}"));
+        for kind in [BroadphaseKind::SpatialHash, BroadphaseKind::SweepAndPrune] {
+            group.bench_function(format!("{kind:?} rebuilt"), |b| {
+                b.iter(|| black_box(count_pairs(&mut AnyBroadphase::new(kind), &balls)))
+            });
+            let mut warm = AnyBroadphase::new(kind);
+            count_pairs(&mut warm, &balls);
+            group.bench_function(format!("{kind:?} persistent"), |b| {
+                b.iter_batched(
+                    || warm.clone(),
+                    |mut broadphase| black_box(count_pairs(&mut broadphase, &moved)),
+                    BatchSize::LargeInput,
+                )
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_broadphases);
+criterion_main!(benches);
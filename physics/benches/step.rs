@@ -0,0 +1,113 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use std::hint::black_box;
+use tagars_physics::synthetic::{self, Masses, WorldSpec};
+use tagars_physics::{
+    step, Ball, Food, SpatialHashable, SpatialIndex, SplitMix64, World, GRID_CELL_SIZE, MAX_SUBSTEP,
+};
+
+fn world() -> World<u64> {
+    WorldSpec::default().build()
+}
+
+fn bench_step(c: &mut Criterion) {
+    let mut group = c.benchmark_group("step 4000 balls");
+    // indexes filled by a previous step, like on the server
+    let mut warm = world();
+    step(&mut warm, &[], 1.0 / 60.0, &mut SplitMix64(0));
+    group.bench_function("persistent indexes", |b| {
+        b.iter_batched(
+            || warm.clone(),
+            |mut world| step(&mut world, &[], 1.0 / 60.0, &mut SplitMix64(0)),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("rebuilt indexes", |b| {
+        b.iter_batched(
+            || {
+                // the same balls and foods, with indexes that are filled from scratch
+                let mut world = World::new(warm.config.clone(), warm.now_micros);
+                world.balls = warm.balls.clone();
+                world.foods = warm.foods.clone();
+                world
+            },
+            |mut world| step(&mut world, &[], 1.0 / 60.0, &mut SplitMix64(0)),
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+// a tick with player inputs for each mass distribution, for the sizes of a busy server
+fn bench_step_masses(c: &mut Criterion) {
+    let mut group = c.benchmark_group("step with inputs");
+    for masses in Masses::ALL {
+        let spec = WorldSpec {
+            masses,
+            ..WorldSpec::default()
+        };
+        let mut rng = SplitMix64(spec.seed);
+        let mut warm = spec.build();
+        step(&mut warm, &[], MAX_SUBSTEP, &mut rng);
+        spec.replenish(&mut warm, &mut rng);
+        let inputs = synthetic::inputs(&warm, &mut rng);
+        group.bench_function(format!("{masses:?}"), |b| {
+            b.iter_batched(
+                || warm.clone(),
+                |mut world| step(&mut world, &inputs, MAX_SUBSTEP, &mut SplitMix64(0)),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+// counts the foods overlapping any ball, which is the work of the food eating phase
+fn bench_food_lookup(c: &mut Criterion) {
+    let world = world();
+    let mut food_index = SpatialIndex::new(GRID_CELL_SIZE);
+    for food in &world.foods {
+        food_index.set(food.id, food.get_aabb());
+    }
+    let foods_by_id = world
+        .foods
+        .iter()
+        .map(|food| (food.id, food))
+        .collect::<std::collections::HashMap<_, _>>();
+    let overlaps = |ball: &Ball<u64>, food: &Food<u64>| {
+        let dx = ball.x - food.x;
+        let dy = ball.y - food.y;
+        (dx * dx + dy * dy).sqrt() < ball.radius
+    };
+
+    let mut group = c.benchmark_group("food lookup 4000 balls");
+    group.bench_function("all pairs", |b| {
+        b.iter(|| {
+            let mut eaten = 0;
+            for ball in &world.balls {
+                for food in &world.foods {
+                    if overlaps(ball, food) {
+                        eaten += 1;
+                    }
+                }
+            }
+            black_box(eaten)
+        })
+    });
+    group.bench_function("spatial index", |b| {
+        b.iter(|| {
+            let mut eaten = 0;
+            for ball in &world.balls {
+                for food_id in food_index.get_for_aabb(ball.get_aabb()) {
+                    if overlaps(ball, foods_by_id[food_id]) {
+                        eaten += 1;
+                    }
+                }
+            }
+            black_box(eaten)
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_step, bench_step_masses, bench_food_lookup);
+criterion_main!(benches);
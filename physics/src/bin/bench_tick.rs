@@ -0,0 +1,105 @@
+//! Runs the physics tick on a synthetic world and reports how long each phase takes.
+//!
+//! cargo run --release --features bench --bin bench_tick -- --balls 4000 --masses heavy-tail --ticks 300
+
+use std::time::Duration;
+use tagars_physics::synthetic::{self, WorldSpec};
+use tagars_physics::{step_timed, BroadphaseKind, SplitMix64, StepTimings, MAX_SUBSTEP};
+
+struct Args {
+    spec: WorldSpec,
+    ticks: u32,
+    warmup_ticks: u32,
+}
+
+fn number<T: std::str::FromStr>(flag: &str, value: String) -> Result<T, String>
+where
+    T::Err: std::fmt::Display,
+{
+    value.parse().map_err(|e| format!("{flag} {value}: {e}"))
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        spec: WorldSpec::default(),
+        ticks: 300,
+        warmup_ticks: 10,
+    };
+    let mut argv = std::env::args().skip(1);
+    while let Some(flag) = argv.next() {
+        let mut value = || argv.next().ok_or(format!("missing value for {flag}"));
+        match flag.as_str() {
+            "--balls" => args.spec.balls = number(&flag, value()?)?,
+            "--foods" => args.spec.foods = number(&flag, value()?)?,
+            "--seed" => args.spec.seed = number(&flag, value()?)?,
+            "--ticks" => args.ticks = number(&flag, value()?)?,
+            "--warmup-ticks" => args.warmup_ticks = number(&flag, value()?)?,
+            "--masses" => args.spec.masses = value()?.parse()?,
+            "--broadphase" => {
+                args.spec.config.broadphase = match value()?.as_str() {
+                    "spatial-hash" => BroadphaseKind::SpatialHash,
+                    "sweep-and-prune" => BroadphaseKind::SweepAndPrune,
+                    other => return Err(format!("unknown broadphase {other:?}")),
+                }
+            }
+            "--help" | "-h" => {
+                return Err(
+                    "usage: bench_tick [--balls N] [--foods N] [--ticks N] [--warmup-ticks N] \
+                     [--seed N] [--masses uniform|heavy-tail|giants] \
+                     [--broadphase spatial-hash|sweep-and-prune]"
+                        .to_string(),
+                )
+            }
+            other => return Err(format!("unknown argument {other:?}, see --help")),
+        }
+    }
+    Ok(args)
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{message}");
+            std::process::exit(2);
+        }
+    };
+
+    let mut world = args.spec.build();
+    let mut rng = SplitMix64(args.spec.seed);
+    let mut timings = StepTimings::default();
+    for tick in 0..args.warmup_ticks + args.ticks {
+        args.spec.replenish(&mut world, &mut rng);
+        let inputs = synthetic::inputs(&world, &mut rng);
+        // warmup ticks fill the persistent indexes, like on a running server
+        let timings = (tick >= args.warmup_ticks).then_some(&mut timings);
+        step_timed(&mut world, &inputs, MAX_SUBSTEP, &mut rng, timings);
+    }
+
+    println!(
+        "{} balls ({:?}), {} foods, {:?}, {} ticks",
+        args.spec.balls,
+        args.spec.masses,
+        args.spec.foods,
+        args.spec.config.broadphase,
+        timings.steps
+    );
+    let ticks = timings.steps.max(1) as f64;
+    for (phase, duration) in timings.phases() {
+        println!("{phase:>14}: {:8.3} ms/tick", millis(duration) / ticks);
+    }
+    println!(
+        "{:>14}: {:8.3} ms/tick",
+        "total",
+        millis(timings.total()) / ticks
+    );
+    println!(
+        "{} balls and {} foods left",
+        world.balls.iter().filter(|ball| !ball.dead).count(),
+        world.foods.len()
+    );
+}
@@ -0,0 +1,249 @@
+use crate::{Aabb, SpatialIndex, GRID_CELL_SIZE};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Finds the pairs of objects that might collide, so the exact test only runs on those.
+/// Implementations are kept in sync with a changing set of objects across steps.
+pub trait Broadphase<K> {
+    /// Inserts `key`, or moves it if it is already present.
+    fn set(&mut self, key: K, aabb: Aabb);
+
+    /// Removes all keys for which `keep` returns false.
+    fn retain(&mut self, keep: &mut dyn FnMut(K) -> bool);
+
+    /// Calls `f` once for every pair of keys whose aabbs overlap, smaller key first.
+    /// Pairs are visited in ascending order, so every implementation reports the same pairs in
+    /// the same order and switching between them doesn't change simulation results.
+    fn for_each_candidate_pair(&mut self, f: &mut dyn FnMut(&K, &K));
+}
+
+/// Which `Broadphase` `step` uses for ball collisions. Only affects speed, not the results.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BroadphaseKind {
+    /// Fast while balls are about as big as a grid cell, slow when huge balls cover many cells.
+    #[default]
+    SpatialHash,
+    /// Independent of ball sizes, slower when many balls line up along the x axis.
+    SweepAndPrune,
+}
+
+impl<K: Copy + Eq + Hash + Ord> Broadphase<K> for SpatialIndex<K> {
+    fn set(&mut self, key: K, aabb: Aabb) {
+        SpatialIndex::set(self, key, aabb)
+    }
+
+    fn retain(&mut self, keep: &mut dyn FnMut(K) -> bool) {
+        SpatialIndex::retain(self, keep)
+    }
+
+    fn for_each_candidate_pair(&mut self, f: &mut dyn FnMut(&K, &K)) {
+        // sharing a cell doesn't mean the aabbs overlap, drop the pairs the other
+        // implementations don't report
+        let index = &*self;
+        index.for_each_candidate_pair(|key1, key2| {
+            let overlap = match (index.aabb(*key1), index.aabb(*key2)) {
+                (Some(aabb1), Some(aabb2)) => aabb1.overlaps(aabb2),
+                _ => false,
+            };
+            if overlap {
+                f(key1, key2);
+            }
+        })
+    }
+}
+
+/// Sort-based broadphase: keeps the aabbs sorted by their left edge and sweeps over them,
+/// pairing every aabb with the ones whose x range it overlaps.
+/// The order is kept across steps, objects move little per step so re-sorting is cheap.
+#[derive(Clone, Debug)]
+pub struct SweepAndPrune<K> {
+    // sorted by `min_x` when sweeping
+    entries: Vec<(K, Aabb)>,
+    positions: HashMap<K, usize>,
+}
+
+impl<K> Default for SweepAndPrune<K> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            positions: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Copy + Eq + Hash> SweepAndPrune<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // the stable sort is adaptive, so it's close to linear for the almost sorted entries of the
+    // previous step
+    fn sort(&mut self) {
+        self.entries.sort_by_key(|(_, aabb)| aabb.min_x);
+        for (idx, (key, _)) in self.entries.iter().enumerate() {
+            self.positions.insert(*key, idx);
+        }
+    }
+}
+
+impl<K: Copy + Eq + Hash + Ord> Broadphase<K> for SweepAndPrune<K> {
+    fn set(&mut self, key: K, aabb: Aabb) {
+        match self.positions.get(&key) {
+            Some(&idx) => self.entries[idx].1 = aabb,
+            None => {
+                self.positions.insert(key, self.entries.len());
+                self.entries.push((key, aabb));
+            }
+        }
+    }
+
+    fn retain(&mut self, keep: &mut dyn FnMut(K) -> bool) {
+        let len = self.entries.len();
+        self.entries.retain(|(key, _)| keep(*key));
+        if self.entries.len() != len {
+            self.positions.clear();
+            for (idx, (key, _)) in self.entries.iter().enumerate() {
+                self.positions.insert(*key, idx);
+            }
+        }
+    }
+
+    fn for_each_candidate_pair(&mut self, f: &mut dyn FnMut(&K, &K)) {
+        self.sort();
+        let mut pairs = Vec::new();
+        for (idx, (key1, aabb1)) in self.entries.iter().enumerate() {
+            for (key2, aabb2) in &self.entries[idx + 1..] {
+                if aabb2.min_x > aabb1.max_x {
+                    // sorted by min_x, so no later entry overlaps either
+                    break;
+                }
+                if aabb1.overlaps(*aabb2) {
+                    pairs.push((*key1.min(key2), *key1.max(key2)));
+                }
+            }
+        }
+        pairs.sort_unstable();
+        for (key1, key2) in &pairs {
+            f(key1, key2);
+        }
+    }
+}
+
+/// One of the `Broadphase` implementations, chosen by a `BroadphaseKind`.
+#[derive(Clone, Debug)]
+pub enum AnyBroadphase<K> {
+    SpatialHash(SpatialIndex<K>),
+    SweepAndPrune(SweepAndPrune<K>),
+}
+
+impl<K: Copy + Eq + Hash> AnyBroadphase<K> {
+    pub fn new(kind: BroadphaseKind) -> Self {
+        match kind {
+            BroadphaseKind::SpatialHash => {
+                AnyBroadphase::SpatialHash(SpatialIndex::new(GRID_CELL_SIZE))
+            }
+            BroadphaseKind::SweepAndPrune => AnyBroadphase::SweepAndPrune(SweepAndPrune::new()),
+        }
+    }
+
+    pub fn kind(&self) -> BroadphaseKind {
+        match self {
+            AnyBroadphase::SpatialHash(_) => BroadphaseKind::SpatialHash,
+            AnyBroadphase::SweepAndPrune(_) => BroadphaseKind::SweepAndPrune,
+        }
+    }
+}
+
+impl<K: Copy + Eq + Hash + Ord> Broadphase<K> for AnyBroadphase<K> {
+    fn set(&mut self, key: K, aabb: Aabb) {
+        match self {
+            AnyBroadphase::SpatialHash(broadphase) => Broadphase::set(broadphase, key, aabb),
+            AnyBroadphase::SweepAndPrune(broadphase) => broadphase.set(key, aabb),
+        }
+    }
+
+    fn retain(&mut self, keep: &mut dyn FnMut(K) -> bool) {
+        match self {
+            AnyBroadphase::SpatialHash(broadphase) => Broadphase::retain(broadphase, keep),
+            AnyBroadphase::SweepAndPrune(broadphase) => broadphase.retain(keep),
+        }
+    }
+
+    fn for_each_candidate_pair(&mut self, f: &mut dyn FnMut(&K, &K)) {
+        match self {
+            AnyBroadphase::SpatialHash(broadphase) => {
+                Broadphase::for_each_candidate_pair(broadphase, f)
+            }
+            AnyBroadphase::SweepAndPrune(broadphase) => broadphase.for_each_candidate_pair(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Rng, SplitMix64};
+
+    fn pairs(broadphase: &mut impl Broadphase<u32>) -> Vec<(u32, u32)> {
+        let mut pairs = Vec::new();
+        broadphase.for_each_candidate_pair(&mut |key1, key2| pairs.push((*key1, *key2)));
+        pairs
+    }
+
+    fn random_aabb(rng: &mut SplitMix64) -> Aabb {
+        let min_x = (rng.next_f64() * 200.0) as i64 - 100;
+        let min_y = (rng.next_f64() * 200.0) as i64 - 100;
+        Aabb {
+            min_x,
+            min_y,
+            max_x: min_x + (rng.next_f64() * 30.0) as i64,
+            max_y: min_y + (rng.next_f64() * 30.0) as i64,
+        }
+    }
+
+    #[test]
+    fn sweep_and_prune_reports_the_same_pairs_as_the_spatial_index() {
+        let mut rng = SplitMix64(7);
+        let mut spatial_index = SpatialIndex::new(GRID_CELL_SIZE);
+        let mut sweep_and_prune = SweepAndPrune::new();
+        for step in 0..5 {
+            for key in 0..60 {
+                let aabb = random_aabb(&mut rng);
+                Broadphase::set(&mut spatial_index, key, aabb);
+                sweep_and_prune.set(key, aabb);
+            }
+            let expected = pairs(&mut spatial_index);
+            assert!(!expected.is_empty(), "step {step}");
+            assert!(expected.windows(2).all(|pair| pair[0] < pair[1]));
+            assert_eq!(pairs(&mut sweep_and_prune), expected, "step {step}");
+        }
+    }
+
+    #[test]
+    fn sweep_and_prune_follows_moves_and_removals() {
+        let aabb = |min_x, min_y| Aabb {
+            min_x,
+            min_y,
+            max_x: min_x + 10,
+            max_y: min_y + 10,
+        };
+        let mut broadphase = SweepAndPrune::new();
+        broadphase.set(1, aabb(0, 0));
+        broadphase.set(2, aabb(6, 6));
+        broadphase.set(3, aabb(100, 0));
+        assert_eq!(pairs(&mut broadphase), vec![(1, 2)]);
+
+        // moving an entry replaces its aabb instead of adding a second one
+        broadphase.set(3, aabb(-5, 0));
+        assert_eq!(pairs(&mut broadphase), vec![(1, 2), (1, 3)]);
+
+        broadphase.retain(&mut |key| key != 1);
+        assert_eq!(pairs(&mut broadphase), vec![]);
+
+        // the remaining entries are still found by key after the removal
+        broadphase.set(2, aabb(-10, 0));
+        broadphase.set(4, aabb(-10, 8));
+        assert_eq!(pairs(&mut broadphase), vec![(2, 3), (2, 4), (3, 4)]);
+        assert_eq!(broadphase.entries.len(), 3);
+    }
+}
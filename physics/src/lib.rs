@@ -0,0 +1,378 @@
+//! The physics of tagars, without any SpacetimeDB dependency.
+//! The server module runs `step` every physics tick, and clients can run the same code natively.
+
+mod broadphase;
+mod spatial_hash_grid;
+mod step;
+#[cfg(feature = "bench")]
+pub mod synthetic;
+
+pub use crate::broadphase::{AnyBroadphase, Broadphase, BroadphaseKind, SweepAndPrune};
+pub use crate::spatial_hash_grid::{
+    Aabb, Cell, Circle, SpatialHashGrid, SpatialHashable, SpatialIndex,
+};
+pub use crate::step::{step, step_timed, Event, Input, StepTimings, MAX_SUBSTEP};
+
+pub const MAX_CELLS_PER_PLAYER: usize = 16;
+pub const SPLIT_SPEED: f64 = 480.0;
+pub const MERGE_MICROS: i64 = 10_000_000;
+pub const EJECT_MASS: f64 = 12.0;
+pub const EJECT_SPEED: f64 = 720.0;
+// below this velocity, a pellet comes to rest
+pub const REST_VELOCITY: f64 = 6.0;
+// below this velocity, a ball falls asleep: it stops and isn't moved until an input or a
+// collision gives it a velocity again
+pub const SLEEP_VELOCITY: f64 = 1.0;
+pub const GRID_CELL_SIZE: i64 = 10;
+// how many of the top leaderboard ranks clients show, and subscribe to besides their own rank
+pub const LEADERBOARD_TOP_N: u32 = 10;
+// balls that move further than this fraction of their radius in a step are tested along their
+// path, so fast balls can't tunnel through foods and other balls
+pub const CCD_TRAVEL_RATIO: f64 = 0.5;
+
+/// Source of randomness for the simulation, so the server can plug in its deterministic RNG.
+pub trait Rng {
+    /// Returns a number in `[0, 1)`.
+    fn next_f64(&mut self) -> f64;
+}
+
+/// A small, seedable RNG for native users of the physics.
+pub struct SplitMix64(pub u64);
+
+impl Rng for SplitMix64 {
+    fn next_f64(&mut self) -> f64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^= z >> 31;
+        // use the upper 53 bits as mantissa
+        (z >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// The parameters of the simulation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    pub world_border_min_x: f64,
+    pub world_border_max_x: f64,
+    pub world_border_min_y: f64,
+    pub world_border_max_y: f64,
+    pub default_radius: f64,
+    pub delta_radius_required_for_eating: f64,
+    // fraction of the velocity that is left after one second
+    pub drag_per_second: f64,
+    // fraction of the approach speed that is left after a bounce off a ball or a wall,
+    // 1 is perfectly elastic and 0 stops the balls
+    pub restitution: f64,
+    pub max_impulse: f64,
+    pub broadphase: BroadphaseKind,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            world_border_min_x: -200.0,
+            world_border_max_x: 200.0,
+            world_border_min_y: -200.0,
+            world_border_max_y: 200.0,
+            default_radius: 4.0,
+            delta_radius_required_for_eating: 3.0,
+            // 0.95 per tick at 60 ticks per second
+            drag_per_second: 0.046,
+            restitution: 1.0,
+            max_impulse: 1200.0,
+            broadphase: BroadphaseKind::SpatialHash,
+        }
+    }
+}
+
+impl Config {
+    pub fn default_mass(&self) -> f64 {
+        mass_of_radius(self.default_radius)
+    }
+
+    // both halves of a split must be at least default_radius big
+    pub fn min_split_radius(&self) -> f64 {
+        self.default_radius * std::f64::consts::SQRT_2
+    }
+
+    pub fn random_pos_in_game_field(&self, rng: &mut impl Rng) -> (f64, f64) {
+        let x = rng.next_f64() * (self.world_border_max_x - self.world_border_min_x)
+            + self.world_border_min_x;
+        let y = rng.next_f64() * (self.world_border_max_y - self.world_border_min_y)
+            + self.world_border_min_y;
+        (x, y)
+    }
+}
+
+pub fn mass_of_radius(radius: f64) -> f64 {
+    radius * radius * std::f64::consts::PI
+}
+
+pub fn radius_of_mass(mass: f64) -> f64 {
+    (mass / std::f64::consts::PI).sqrt()
+}
+
+/// Everything `step` simulates. `O` identifies the owner of a cell, the server uses `Identity`.
+#[derive(Clone, Debug)]
+pub struct World<O> {
+    pub config: Config,
+    // the time of this state, `step` advances it
+    pub now_micros: i64,
+    pub balls: Vec<Ball<O>>,
+    pub foods: Vec<Food<O>>,
+    pub viruses: Vec<Virus>,
+    // alive balls and foods by id, as of the last step. `step` keeps them in sync with `balls`
+    // and `foods`, so keep the world (or at least these) around across steps instead of
+    // rebuilding them. `step` replaces the broadphase if `config.broadphase` asks for another one.
+    pub ball_broadphase: AnyBroadphase<u64>,
+    pub food_index: SpatialIndex<u64>,
+}
+
+impl<O> World<O> {
+    pub fn new(config: Config, now_micros: i64) -> Self {
+        World {
+            ball_broadphase: AnyBroadphase::new(config.broadphase),
+            food_index: SpatialIndex::new(GRID_CELL_SIZE),
+            config,
+            now_micros,
+            balls: Vec::new(),
+            foods: Vec::new(),
+            viruses: Vec::new(),
+        }
+    }
+}
+
+/// A player cell. Cells created by `step` have id 0 until whoever stores them assigns one.
+/// Ids of cells passed to `step` must be unique, cells with id 0 don't collide with other cells.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ball<O> {
+    pub id: u64,
+    pub owner: O,
+    pub x: f64,
+    pub y: f64,
+    pub vx: f64,
+    pub vy: f64,
+    pub radius: f64,
+    pub dead: bool,
+    // cells of the same owner bounce off each other until this time, after which they merge on contact
+    pub merge_at_micros: i64,
+}
+
+impl<O: Copy + PartialEq> Ball<O> {
+    pub fn is_moving(&self) -> bool {
+        self.vx != 0.0 || self.vy != 0.0
+    }
+
+    pub fn mass(&self) -> f64 {
+        mass_of_radius(self.radius)
+    }
+
+    pub fn update_mass(&mut self, new_mass: f64) {
+        self.radius = radius_of_mass(new_mass);
+    }
+
+    pub fn add_mass(&mut self, mass: f64) {
+        self.update_mass(self.mass() + mass);
+    }
+
+    pub fn apply_impulse(&mut self, config: &Config, impulse_x: f64, impulse_y: f64) {
+        // take into account ball's mass
+        // heavier balls get a less significant velocity change from the same impulse.
+        // balls can be smaller than default_radius if it was raised while they were alive
+        let size = (self.radius - config.default_radius + 1.0).max(1.0);
+        let impulse_x = impulse_x / size.sqrt();
+        let impulse_y = impulse_y / size.sqrt();
+
+        self.vx += impulse_x;
+        self.vy += impulse_y;
+
+        // cap max velocity, different max per radius
+        let max_velocity = 600.0 / size.powf(0.8);
+        let velocity = self.vx.hypot(self.vy);
+        if velocity > max_velocity {
+            let scale = max_velocity / velocity;
+            self.vx *= scale;
+            self.vy *= scale;
+        }
+    }
+
+    /// Splits off half of this cell's mass into a new cell launched in direction `dir`,
+    /// which must be normalized.
+    pub fn split(&mut self, dir_x: f64, dir_y: f64, merge_at_micros: i64) -> Ball<O> {
+        self.update_mass(self.mass() / 2.0);
+        self.merge_at_micros = merge_at_micros;
+        Ball {
+            id: 0,
+            x: self.x + dir_x * self.radius,
+            y: self.y + dir_y * self.radius,
+            vx: self.vx + dir_x * SPLIT_SPEED,
+            vy: self.vy + dir_y * SPLIT_SPEED,
+            ..self.clone()
+        }
+    }
+
+    /// Ejects a pellet of `EJECT_MASS` in direction `dir`, which must be normalized.
+    pub fn eject_mass(&mut self, dir_x: f64, dir_y: f64) -> Food<O> {
+        self.update_mass(self.mass() - EJECT_MASS);
+        Food {
+            id: 0,
+            // spawn just outside the cell
+            x: self.x + dir_x * (self.radius + 1.0),
+            y: self.y + dir_y * (self.radius + 1.0),
+            vx: self.vx + dir_x * EJECT_SPEED,
+            vy: self.vy + dir_y * EJECT_SPEED,
+            mass: EJECT_MASS,
+            owner: Some(self.owner),
+        }
+    }
+
+    /// Pops this cell into up to `pieces` equally sized cells flying apart in all directions.
+    /// This cell becomes one of the pieces, the other ones are returned.
+    pub fn pop(&mut self, config: &Config, pieces: usize, merge_at_micros: i64) -> Vec<Ball<O>> {
+        // every piece must be at least as big as a freshly spawned ball
+        let pieces = pieces.min((self.mass() / config.default_mass()) as usize);
+        if pieces < 2 {
+            return Vec::new();
+        }
+
+        self.update_mass(self.mass() / pieces as f64);
+        self.merge_at_micros = merge_at_micros;
+        (1..pieces)
+            .map(|i| {
+                let angle = i as f64 / pieces as f64 * std::f64::consts::TAU;
+                let (dir_y, dir_x) = angle.sin_cos();
+                Ball {
+                    id: 0,
+                    x: self.x + dir_x * self.radius,
+                    y: self.y + dir_y * self.radius,
+                    vx: self.vx + dir_x * SPLIT_SPEED,
+                    vy: self.vy + dir_y * SPLIT_SPEED,
+                    ..self.clone()
+                }
+            })
+            .collect()
+    }
+}
+
+impl<O> SpatialHashable for Ball<O> {
+    fn get_aabb(&self) -> Aabb {
+        Aabb {
+            min_x: (self.x - self.radius).floor() as i64,
+            min_y: (self.y - self.radius).floor() as i64,
+            max_x: (self.x + self.radius).floor() as i64,
+            max_y: (self.y + self.radius).floor() as i64,
+        }
+    }
+
+    fn get_circle(&self) -> Circle {
+        Circle {
+            x: self.x,
+            y: self.y,
+            radius: self.radius,
+        }
+    }
+}
+
+/// A food pellet. Pellets ejected by a player move and can't be eaten by that player until they rest.
+/// Ids of foods passed to `step` must be unique.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Food<O> {
+    pub id: u64,
+    pub x: f64,
+    pub y: f64,
+    pub vx: f64,
+    pub vy: f64,
+    pub mass: f64,
+    // the player that ejected this pellet, until it comes to rest
+    pub owner: Option<O>,
+}
+
+impl<O> Food<O> {
+    pub fn is_moving(&self) -> bool {
+        self.vx != 0.0 || self.vy != 0.0
+    }
+}
+
+impl<O> SpatialHashable for Food<O> {
+    fn get_aabb(&self) -> Aabb {
+        Aabb {
+            min_x: self.x.floor() as i64,
+            min_y: self.y.floor() as i64,
+            max_x: self.x.floor() as i64,
+            max_y: self.y.floor() as i64,
+        }
+    }
+
+    // balls eat a food once they cover its center, so it's a point
+    fn get_circle(&self) -> Circle {
+        Circle {
+            x: self.x,
+            y: self.y,
+            radius: 0.0,
+        }
+    }
+}
+
+/// A virus pops big cells that eat it, and splits when fed with pellets.
+/// Viruses created by `step` have id 0 until whoever stores them assigns one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Virus {
+    pub id: u64,
+    pub x: f64,
+    pub y: f64,
+    pub radius: f64,
+    // number of ejected pellets this virus has been fed since it last spawned a new virus
+    pub fed: u32,
+}
+
+impl Virus {
+    pub const RADIUS: f64 = 10.0;
+    pub const FEEDS_TO_SPAWN: u32 = 7;
+    // feeding can grow the number of viruses up to this many
+    pub const MAX_VIRUSES: usize = 30;
+    // balls need to be this much bigger than a virus to get popped by it, smaller ones pass under it
+    pub const POP_RADIUS_RATIO: f64 = 1.15;
+    pub const POP_PIECES: usize = 8;
+
+    pub fn mass(&self) -> f64 {
+        mass_of_radius(self.radius)
+    }
+
+    /// Creates a virus at the given position, moved inside the world borders if necessary.
+    pub fn new_at(config: &Config, x: f64, y: f64) -> Self {
+        Virus {
+            id: 0,
+            x: x.clamp(
+                config.world_border_min_x + Virus::RADIUS,
+                config.world_border_max_x - Virus::RADIUS,
+            ),
+            y: y.clamp(
+                config.world_border_min_y + Virus::RADIUS,
+                config.world_border_max_y - Virus::RADIUS,
+            ),
+            radius: Virus::RADIUS,
+            fed: 0,
+        }
+    }
+}
+
+impl SpatialHashable for Virus {
+    fn get_aabb(&self) -> Aabb {
+        Aabb {
+            min_x: (self.x - self.radius).floor() as i64,
+            min_y: (self.y - self.radius).floor() as i64,
+            max_x: (self.x + self.radius).floor() as i64,
+            max_y: (self.y + self.radius).floor() as i64,
+        }
+    }
+
+    fn get_circle(&self) -> Circle {
+        Circle {
+            x: self.x,
+            y: self.y,
+            radius: self.radius,
+        }
+    }
+}
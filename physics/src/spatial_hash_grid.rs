@@ -0,0 +1,657 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Cell {
+    pub x: i64,
+    pub y: i64,
+}
+
+pub trait SpatialHashable {
+    fn get_aabb(&self) -> Aabb;
+
+    /// The exact shape, used to filter the results of the distance queries.
+    /// It must lie within the cells of `get_aabb`.
+    fn get_circle(&self) -> Circle;
+}
+
+/// A circle in world coordinates. Points are circles with radius 0.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Circle {
+    pub x: f64,
+    pub y: f64,
+    pub radius: f64,
+}
+
+impl Circle {
+    /// Distance from `(x, y)` to the edge of the circle, 0 if the point is inside.
+    pub fn distance_to(&self, x: f64, y: f64) -> f64 {
+        let dx = x - self.x;
+        let dy = y - self.y;
+        ((dx * dx + dy * dy).sqrt() - self.radius).max(0.0)
+    }
+
+    /// The smallest `t >= 0` at which the ray `origin + t * dir` hits the circle, if any.
+    /// `dir` must be normalized. Rays starting inside the circle hit it at `t = 0`.
+    fn ray_hit(&self, origin_x: f64, origin_y: f64, dir_x: f64, dir_y: f64) -> Option<f64> {
+        let to_center_x = self.x - origin_x;
+        let to_center_y = self.y - origin_y;
+        let c = to_center_x * to_center_x + to_center_y * to_center_y - self.radius * self.radius;
+        if c <= 0.0 {
+            return Some(0.0);
+        }
+        let b = to_center_x * dir_x + to_center_y * dir_y;
+        let discriminant = b * b - c;
+        if b <= 0.0 || discriminant < 0.0 {
+            return None;
+        }
+        Some(b - discriminant.sqrt())
+    }
+}
+
+/// Axis-aligned bounding box, in world coordinates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Aabb {
+    pub min_x: i64,
+    pub min_y: i64,
+    pub max_x: i64,
+    pub max_y: i64,
+}
+
+impl Aabb {
+    /// The smallest `Aabb` containing both.
+    pub fn union(self, other: Aabb) -> Aabb {
+        Aabb {
+            min_x: self.min_x.min(other.min_x),
+            min_y: self.min_y.min(other.min_y),
+            max_x: self.max_x.max(other.max_x),
+            max_y: self.max_y.max(other.max_y),
+        }
+    }
+
+    /// Whether the two share at least one point, touching edges count.
+    pub fn overlaps(self, other: Aabb) -> bool {
+        self.min_x <= other.max_x
+            && other.min_x <= self.max_x
+            && self.min_y <= other.max_y
+            && other.min_y <= self.max_y
+    }
+
+    fn get_cells(&self, cell_size: i64) -> CellRange {
+        // we want to do a floor division here to get consistent behavior across sign changes
+        CellRange {
+            min_x: self.min_x.div_euclid(cell_size),
+            min_y: self.min_y.div_euclid(cell_size),
+            max_x: self.max_x.div_euclid(cell_size),
+            max_y: self.max_y.div_euclid(cell_size),
+        }
+    }
+}
+
+/// The cells an `Aabb` overlaps, inclusive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct CellRange {
+    min_x: i64,
+    min_y: i64,
+    max_x: i64,
+    max_y: i64,
+}
+
+impl CellRange {
+    fn contains(&self, cell: Cell) -> bool {
+        (self.min_x..=self.max_x).contains(&cell.x) && (self.min_y..=self.max_y).contains(&cell.y)
+    }
+
+    fn union(&self, other: CellRange) -> CellRange {
+        CellRange {
+            min_x: self.min_x.min(other.min_x),
+            min_y: self.min_y.min(other.min_y),
+            max_x: self.max_x.max(other.max_x),
+            max_y: self.max_y.max(other.max_y),
+        }
+    }
+
+    fn iter(self) -> impl Iterator<Item = Cell> {
+        (self.min_x..=self.max_x)
+            .flat_map(move |x| (self.min_y..=self.max_y).map(move |y| Cell { x, y }))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SpatialHashGrid<T> {
+    grid: HashMap<Cell, Vec<T>>,
+    cell_size: i64,
+    // covers all cells that were used since the last `clear`, bounds the nearest neighbour search
+    bounds: Option<CellRange>,
+}
+
+impl<T> SpatialHashGrid<T> {
+    pub fn new(cell_size: i64) -> Self {
+        Self {
+            grid: HashMap::new(),
+            cell_size,
+            bounds: None,
+        }
+    }
+
+    pub fn insert(&mut self, item: T)
+    where
+        T: SpatialHashable + Clone,
+    {
+        let aabb = item.get_aabb();
+        self.insert_with_aabb(item, aabb)
+    }
+
+    pub fn insert_with_aabb(&mut self, item: T, aabb: Aabb)
+    where
+        T: Clone,
+    {
+        let cells = aabb.get_cells(self.cell_size);
+        self.extend_bounds(cells);
+        for cell in cells.iter() {
+            self.grid.entry(cell).or_default().push(item.clone());
+        }
+    }
+
+    fn extend_bounds(&mut self, cells: CellRange) {
+        self.bounds = Some(self.bounds.map_or(cells, |bounds| bounds.union(cells)));
+    }
+
+    /// Removes `item` from the cells of `aabb`, which must be the aabb it was inserted with.
+    pub fn remove(&mut self, item: &T, aabb: Aabb)
+    where
+        T: PartialEq,
+    {
+        for cell in aabb.get_cells(self.cell_size).iter() {
+            self.remove_from_cell(item, cell);
+        }
+    }
+
+    /// Moves `item` from the cells of `old_aabb` to the cells of `new_aabb`.
+    /// Only the cells that differ are touched, so small moves are cheap.
+    pub fn update(&mut self, item: T, old_aabb: Aabb, new_aabb: Aabb)
+    where
+        T: PartialEq + Clone,
+    {
+        let old_cells = old_aabb.get_cells(self.cell_size);
+        let new_cells = new_aabb.get_cells(self.cell_size);
+        if old_cells == new_cells {
+            return;
+        }
+        self.extend_bounds(new_cells);
+        for cell in old_cells.iter().filter(|cell| !new_cells.contains(*cell)) {
+            self.remove_from_cell(&item, cell);
+        }
+        for cell in new_cells.iter().filter(|cell| !old_cells.contains(*cell)) {
+            self.grid.entry(cell).or_default().push(item.clone());
+        }
+    }
+
+    fn remove_from_cell(&mut self, item: &T, cell: Cell)
+    where
+        T: PartialEq,
+    {
+        let Some(items) = self.grid.get_mut(&cell) else {
+            return;
+        };
+        if let Some(idx) = items.iter().position(|other| other == item) {
+            items.swap_remove(idx);
+        }
+        if items.is_empty() {
+            self.grid.remove(&cell);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.grid.clear();
+        self.bounds = None;
+    }
+
+    pub fn get(&self, cell: Cell) -> impl Iterator<Item = &T> {
+        self.grid.get(&cell).into_iter().flat_map(|v| v.iter())
+    }
+
+    /// All items in the cells overlapping `aabb`. Items spanning multiple cells are returned once
+    /// per shared cell, see `query_unique` for a deduplicated version.
+    pub fn get_for_aabb(&self, aabb: Aabb) -> impl Iterator<Item = &T> {
+        aabb.get_cells(self.cell_size)
+            .iter()
+            .flat_map(move |cell| self.get(cell))
+    }
+
+    /// The distinct items in the cells overlapping `aabb`, in ascending order.
+    pub fn query_unique(&self, aabb: Aabb) -> Vec<T>
+    where
+        T: Ord + Clone,
+    {
+        let mut items = self.get_for_aabb(aabb).cloned().collect::<Vec<_>>();
+        items.sort_unstable();
+        items.dedup();
+        items
+    }
+
+    /// Calls `f` once for every pair of distinct items sharing at least one cell, smaller item
+    /// first. Pairs are visited in ascending order, so the result doesn't depend on the order of
+    /// insertion or of the cells in the grid.
+    pub fn for_each_candidate_pair(&self, mut f: impl FnMut(&T, &T))
+    where
+        T: Ord + Clone,
+    {
+        let mut pairs = Vec::new();
+        for items in self.grid.values() {
+            for (idx, item1) in items.iter().enumerate() {
+                for item2 in &items[idx + 1..] {
+                    match item1.cmp(item2) {
+                        std::cmp::Ordering::Less => pairs.push((item1.clone(), item2.clone())),
+                        std::cmp::Ordering::Greater => pairs.push((item2.clone(), item1.clone())),
+                        std::cmp::Ordering::Equal => {}
+                    }
+                }
+            }
+        }
+        pairs.sort_unstable();
+        pairs.dedup();
+        for (item1, item2) in &pairs {
+            f(item1, item2);
+        }
+    }
+
+    /// All items whose circle overlaps the circle of `radius` around `(x, y)`, each once.
+    ///
+    /// The distance queries assume items were inserted with their own `get_aabb`.
+    pub fn query_radius(&self, x: f64, y: f64, radius: f64) -> Vec<&T>
+    where
+        T: SpatialHashable,
+    {
+        let query = Aabb {
+            min_x: (x - radius).floor() as i64,
+            min_y: (y - radius).floor() as i64,
+            max_x: (x + radius).floor() as i64,
+            max_y: (y + radius).floor() as i64,
+        }
+        .get_cells(self.cell_size);
+        let mut found = Vec::new();
+        for cell in query.iter() {
+            for item in self.get(cell) {
+                // an item spanning multiple cells is only reported from the first cell it shares
+                // with the query
+                let cells = item.get_aabb().get_cells(self.cell_size);
+                let first_shared = Cell {
+                    x: cells.min_x.max(query.min_x),
+                    y: cells.min_y.max(query.min_y),
+                };
+                if cell == first_shared && item.get_circle().distance_to(x, y) <= radius {
+                    found.push(item);
+                }
+            }
+        }
+        found
+    }
+
+    /// The `k` items closest to `(x, y)`, closest first. Distances are measured to the edge of
+    /// the items' circles, so items containing the point have distance 0.
+    pub fn nearest(&self, x: f64, y: f64, k: usize) -> Vec<&T>
+    where
+        T: SpatialHashable,
+    {
+        let Some(bounds) = self.bounds else {
+            return Vec::new();
+        };
+        if k == 0 {
+            return Vec::new();
+        }
+        // a radius that reaches every cell that was ever used
+        let cell_size = self.cell_size as f64;
+        let min_x = bounds.min_x as f64 * cell_size;
+        let min_y = bounds.min_y as f64 * cell_size;
+        let max_x = (bounds.max_x + 1) as f64 * cell_size;
+        let max_y = (bounds.max_y + 1) as f64 * cell_size;
+        let max_dx = (x - min_x).abs().max((x - max_x).abs());
+        let max_dy = (y - min_y).abs().max((y - max_y).abs());
+        let max_radius = max_dx.hypot(max_dy);
+
+        // grow the search radius until it contains k items, those are the k closest
+        let mut radius = cell_size;
+        loop {
+            let mut found = self.query_radius(x, y, radius);
+            if found.len() >= k || radius >= max_radius {
+                found.sort_by(|a, b| {
+                    let distance_a = a.get_circle().distance_to(x, y);
+                    let distance_b = b.get_circle().distance_to(x, y);
+                    distance_a.total_cmp(&distance_b)
+                });
+                found.truncate(k);
+                return found;
+            }
+            radius *= 2.0;
+        }
+    }
+
+    /// The first item hit by the ray from `(origin_x, origin_y)` in direction `(dir_x, dir_y)`
+    /// within `max_distance`, and the distance at which it is hit.
+    pub fn raycast(
+        &self,
+        origin_x: f64,
+        origin_y: f64,
+        dir_x: f64,
+        dir_y: f64,
+        max_distance: f64,
+    ) -> Option<(&T, f64)>
+    where
+        T: SpatialHashable,
+    {
+        let bounds = self.bounds?;
+        let length = dir_x.hypot(dir_y);
+        if length == 0.0 || !length.is_finite() || !max_distance.is_finite() {
+            return None;
+        }
+        let (dir_x, dir_y) = (dir_x / length, dir_y / length);
+
+        // walk the cells along the ray, see Amanatides and Woo, "A Fast Voxel Traversal Algorithm"
+        let cell_size = self.cell_size as f64;
+        let mut cell = Cell {
+            x: (origin_x / cell_size).floor() as i64,
+            y: (origin_y / cell_size).floor() as i64,
+        };
+        let step_x = if dir_x > 0.0 { 1 } else { -1 };
+        let step_y = if dir_y > 0.0 { 1 } else { -1 };
+        // distance along the ray to the next cell border, per axis
+        let border_distance = |position: f64, cell: i64, step: i64, dir: f64| {
+            if dir == 0.0 {
+                return f64::INFINITY;
+            }
+            let border = (cell + (step + 1) / 2) as f64 * cell_size;
+            (border - position) / dir
+        };
+        let mut next_x = border_distance(origin_x, cell.x, step_x, dir_x);
+        let mut next_y = border_distance(origin_y, cell.y, step_y, dir_y);
+        let delta_x = (cell_size / dir_x).abs();
+        let delta_y = (cell_size / dir_y).abs();
+
+        let mut best: Option<(&T, f64)> = None;
+        loop {
+            for item in self.get(cell) {
+                let Some(distance) = item.get_circle().ray_hit(origin_x, origin_y, dir_x, dir_y)
+                else {
+                    continue;
+                };
+                if distance <= max_distance && best.is_none_or(|(_, best)| distance < best) {
+                    best = Some((item, distance));
+                }
+            }
+            // items in later cells can't be hit before the ray leaves this cell
+            let cell_exit = next_x.min(next_y);
+            if best.is_some_and(|(_, best)| best <= cell_exit) || cell_exit > max_distance {
+                return best;
+            }
+            // outside of the used cells and not heading towards them, nothing can be hit anymore
+            let past_x =
+                (cell.x > bounds.max_x && dir_x >= 0.0) || (cell.x < bounds.min_x && dir_x <= 0.0);
+            let past_y =
+                (cell.y > bounds.max_y && dir_y >= 0.0) || (cell.y < bounds.min_y && dir_y <= 0.0);
+            if past_x || past_y {
+                return best;
+            }
+            if next_x < next_y {
+                cell.x += step_x;
+                next_x += delta_x;
+            } else {
+                cell.y += step_y;
+                next_y += delta_y;
+            }
+        }
+    }
+}
+
+/// A `SpatialHashGrid` that remembers the aabb of every key, so it can be kept in sync with a
+/// changing set of objects across ticks instead of being rebuilt every time.
+#[derive(Clone, Debug)]
+pub struct SpatialIndex<K> {
+    grid: SpatialHashGrid<K>,
+    aabbs: HashMap<K, Aabb>,
+}
+
+impl<K: Copy + Eq + Hash> SpatialIndex<K> {
+    pub fn new(cell_size: i64) -> Self {
+        Self {
+            grid: SpatialHashGrid::new(cell_size),
+            aabbs: HashMap::new(),
+        }
+    }
+
+    /// Inserts `key`, or moves it if it is already present.
+    pub fn set(&mut self, key: K, aabb: Aabb) {
+        match self.aabbs.insert(key, aabb) {
+            Some(old_aabb) => self.grid.update(key, old_aabb, aabb),
+            None => self.grid.insert_with_aabb(key, aabb),
+        }
+    }
+
+    pub fn remove(&mut self, key: K) {
+        if let Some(aabb) = self.aabbs.remove(&key) {
+            self.grid.remove(&key, aabb);
+        }
+    }
+
+    /// Removes all keys for which `keep` returns false.
+    pub fn retain(&mut self, mut keep: impl FnMut(K) -> bool) {
+        let grid = &mut self.grid;
+        self.aabbs.retain(|key, aabb| {
+            let keep = keep(*key);
+            if !keep {
+                grid.remove(key, *aabb);
+            }
+            keep
+        });
+    }
+
+    pub fn clear(&mut self) {
+        self.grid.clear();
+        self.aabbs.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.aabbs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.aabbs.is_empty()
+    }
+
+    pub fn aabb(&self, key: K) -> Option<Aabb> {
+        self.aabbs.get(&key).copied()
+    }
+
+    /// All keys in the cells overlapping `aabb`. Keys spanning multiple cells may be returned
+    /// more than once, and keys whose aabb doesn't overlap `aabb` itself may be returned too.
+    pub fn get_for_aabb(&self, aabb: Aabb) -> impl Iterator<Item = &K> {
+        self.grid.get_for_aabb(aabb)
+    }
+
+    /// See `SpatialHashGrid::query_unique`.
+    pub fn query_unique(&self, aabb: Aabb) -> Vec<K>
+    where
+        K: Ord,
+    {
+        self.grid.query_unique(aabb)
+    }
+
+    /// See `SpatialHashGrid::for_each_candidate_pair`.
+    pub fn for_each_candidate_pair(&self, f: impl FnMut(&K, &K))
+    where
+        K: Ord,
+    {
+        self.grid.for_each_candidate_pair(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Disc {
+        id: u32,
+        circle: Circle,
+    }
+
+    impl SpatialHashable for Disc {
+        fn get_aabb(&self) -> Aabb {
+            Aabb {
+                min_x: (self.circle.x - self.circle.radius).floor() as i64,
+                min_y: (self.circle.y - self.circle.radius).floor() as i64,
+                max_x: (self.circle.x + self.circle.radius).floor() as i64,
+                max_y: (self.circle.y + self.circle.radius).floor() as i64,
+            }
+        }
+
+        fn get_circle(&self) -> Circle {
+            self.circle
+        }
+    }
+
+    fn grid(discs: &[(f64, f64, f64)]) -> SpatialHashGrid<Disc> {
+        let mut grid = SpatialHashGrid::new(10);
+        for (id, &(x, y, radius)) in discs.iter().enumerate() {
+            grid.insert(Disc {
+                id: id as u32,
+                circle: Circle { x, y, radius },
+            });
+        }
+        grid
+    }
+
+    fn ids<'a>(discs: impl IntoIterator<Item = &'a Disc>) -> Vec<u32> {
+        discs.into_iter().map(|disc| disc.id).collect()
+    }
+
+    fn sorted_ids<'a>(discs: impl IntoIterator<Item = &'a Disc>) -> Vec<u32> {
+        let mut ids = ids(discs);
+        ids.sort();
+        ids
+    }
+
+    #[test]
+    fn query_radius_filters_by_exact_distance() {
+        // the second disc is in a queried cell, but too far away
+        let grid = grid(&[(1.0, 1.0, 0.0), (9.0, 9.0, 0.0), (-3.0, 0.0, 2.0)]);
+        assert_eq!(sorted_ids(grid.query_radius(0.0, 0.0, 2.0)), vec![0, 2]);
+    }
+
+    #[test]
+    fn query_radius_reports_items_spanning_cells_once() {
+        let grid = grid(&[(0.0, 0.0, 25.0)]);
+        assert_eq!(ids(grid.query_radius(0.0, 0.0, 30.0)), vec![0]);
+        assert_eq!(ids(grid.query_radius(15.0, 15.0, 1.0)), vec![0]);
+    }
+
+    #[test]
+    fn query_radius_works_across_negative_coordinates() {
+        let grid = grid(&[(-0.5, -0.5, 0.0), (0.5, 0.5, 0.0), (-15.0, 3.0, 0.0)]);
+        assert_eq!(sorted_ids(grid.query_radius(0.0, 0.0, 1.0)), vec![0, 1]);
+        assert_eq!(ids(grid.query_radius(-14.0, 3.0, 1.0)), vec![2]);
+    }
+
+    #[test]
+    fn nearest_returns_closest_first() {
+        let grid = grid(&[
+            (100.0, 0.0, 0.0),
+            (5.0, 0.0, 0.0),
+            (-30.0, 0.0, 0.0),
+            (0.0, 12.0, 0.0),
+        ]);
+        assert_eq!(ids(grid.nearest(0.0, 0.0, 3)), vec![1, 3, 2]);
+    }
+
+    #[test]
+    fn nearest_measures_to_the_edge() {
+        // the big disc's edge is closer than the small disc
+        let grid = grid(&[(3.0, 0.0, 0.0), (50.0, 0.0, 48.0)]);
+        assert_eq!(ids(grid.nearest(0.0, 0.0, 1)), vec![1]);
+    }
+
+    #[test]
+    fn nearest_returns_everything_if_there_are_fewer_than_k() {
+        let grid = grid(&[(1000.0, 0.0, 0.0), (0.0, -2000.0, 0.0)]);
+        assert_eq!(ids(grid.nearest(0.0, 0.0, 5)), vec![0, 1]);
+        assert!(SpatialHashGrid::<Disc>::new(10)
+            .nearest(0.0, 0.0, 5)
+            .is_empty());
+    }
+
+    #[test]
+    fn raycast_hits_the_first_item() {
+        let grid = grid(&[(50.0, 0.0, 2.0), (25.0, 1.0, 2.0), (25.0, 20.0, 2.0)]);
+        let (disc, distance) = grid.raycast(0.0, 0.0, 1.0, 0.0, 100.0).unwrap();
+        assert_eq!(disc.id, 1);
+        assert!((distance - (25.0 - 3.0f64.sqrt())).abs() < 1e-9);
+    }
+
+    #[test]
+    fn raycast_respects_direction_and_max_distance() {
+        let grid = grid(&[(50.0, 50.0, 2.0), (-30.0, 0.0, 1.0)]);
+        let (disc, _) = grid.raycast(0.0, 0.0, 1.0, 1.0, 100.0).unwrap();
+        assert_eq!(disc.id, 0);
+        assert!(grid.raycast(0.0, 0.0, 1.0, 1.0, 60.0).is_none());
+        assert!(grid.raycast(0.0, 0.0, 0.0, 1.0, 1000.0).is_none());
+        let (disc, distance) = grid.raycast(0.0, 0.0, -1.0, 0.0, 1000.0).unwrap();
+        assert_eq!(disc.id, 1);
+        assert!((distance - 29.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn raycast_from_inside_an_item_hits_it_immediately() {
+        let grid = grid(&[(0.0, 0.0, 30.0), (5.0, 0.0, 1.0)]);
+        let (disc, distance) = grid.raycast(1.0, 0.0, 1.0, 0.0, 100.0).unwrap();
+        assert_eq!((disc.id, distance), (0, 0.0));
+    }
+
+    #[test]
+    fn update_moves_items_between_cells() {
+        let mut grid = SpatialHashGrid::new(10);
+        let old = Aabb {
+            min_x: 0,
+            min_y: 0,
+            max_x: 15,
+            max_y: 5,
+        };
+        let new = Aabb {
+            min_x: 12,
+            min_y: 0,
+            max_x: 25,
+            max_y: 5,
+        };
+        grid.insert_with_aabb(7, old);
+        grid.update(7, old, new);
+        assert_eq!(grid.get(Cell { x: 0, y: 0 }).count(), 0);
+        assert_eq!(grid.get(Cell { x: 1, y: 0 }).collect::<Vec<_>>(), vec![&7]);
+        assert_eq!(grid.get(Cell { x: 2, y: 0 }).collect::<Vec<_>>(), vec![&7]);
+        grid.remove(&7, new);
+        assert_eq!(grid.get_for_aabb(new).count(), 0);
+    }
+
+    #[test]
+    fn candidate_pairs_are_unique_and_ordered() {
+        let mut grid = SpatialHashGrid::new(10);
+        let big = Aabb {
+            min_x: 0,
+            min_y: 0,
+            max_x: 25,
+            max_y: 25,
+        };
+        let small = |x, y| Aabb {
+            min_x: x,
+            min_y: y,
+            max_x: x,
+            max_y: y,
+        };
+        grid.insert_with_aabb(3, big);
+        grid.insert_with_aabb(1, small(5, 5));
+        grid.insert_with_aabb(2, small(15, 15));
+        grid.insert_with_aabb(4, small(100, 100));
+        let mut pairs = Vec::new();
+        grid.for_each_candidate_pair(|a, b| pairs.push((*a, *b)));
+        assert_eq!(pairs, vec![(1, 3), (2, 3)]);
+        assert_eq!(grid.query_unique(big), vec![1, 2, 3]);
+    }
+}
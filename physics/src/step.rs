@@ -0,0 +1,1097 @@
+use crate::{
+    AnyBroadphase, Ball, Broadphase, Config, Rng, SpatialHashGrid, SpatialHashable, Virus, World,
+    CCD_TRAVEL_RATIO, GRID_CELL_SIZE, MAX_CELLS_PER_PLAYER, MERGE_MICROS, REST_VELOCITY,
+    SLEEP_VELOCITY,
+};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+/// Longer steps are simulated in multiple substeps of at most this many seconds.
+pub const MAX_SUBSTEP: f64 = 1.0 / 60.0;
+
+/// An impulse a player wants to apply to all of their cells.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Input<O> {
+    pub owner: O,
+    pub impulse_x: f64,
+    pub impulse_y: f64,
+}
+
+/// Something that happened during a `step`, that whoever runs the simulation may need to react to.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Event<O> {
+    /// A cell was eaten by a cell of `eaten_by`. Eaten cells are removed from the world,
+    /// unless it was the last cell of its owner, see `PlayerDied`.
+    BallEaten {
+        id: u64,
+        owner: O,
+        eaten_by: O,
+    },
+    /// All cells of `owner` were eaten. Its last cell stays in the world marked as dead,
+    /// so it can be respawned.
+    PlayerDied {
+        owner: O,
+    },
+    /// A cell ate a virus and popped into `pieces` cells.
+    BallPopped {
+        owner: O,
+        pieces: usize,
+    },
+    FoodEaten {
+        id: u64,
+        eaten_by: O,
+    },
+    VirusEaten {
+        id: u64,
+        eaten_by: O,
+    },
+    /// A virus was fed enough to spawn a new one. The new virus is added to the world with id 0.
+    VirusSpawned {
+        parent_id: u64,
+    },
+}
+
+/// Time spent in each phase of `step`, summed over all steps it was passed to.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StepTimings {
+    pub steps: u32,
+    pub inputs: Duration,
+    pub movement: Duration,
+    pub pellets: Duration,
+    pub virus_feeding: Duration,
+    pub food_eating: Duration,
+    pub virus_pops: Duration,
+    pub collisions: Duration,
+    pub cleanup: Duration,
+}
+
+impl StepTimings {
+    pub fn phases(&self) -> [(&'static str, Duration); 8] {
+        [
+            ("inputs", self.inputs),
+            ("movement", self.movement),
+            ("pellets", self.pellets),
+            ("virus feeding", self.virus_feeding),
+            ("food eating", self.food_eating),
+            ("virus pops", self.virus_pops),
+            ("collisions", self.collisions),
+            ("cleanup", self.cleanup),
+        ]
+    }
+
+    pub fn total(&self) -> Duration {
+        self.phases().iter().map(|(_, duration)| *duration).sum()
+    }
+}
+
+// Measures the phases of a step, if there is anything to measure into.
+// Clocks aren't available in the server module, so it must not read one otherwise.
+struct PhaseTimer<'a> {
+    timings: Option<&'a mut StepTimings>,
+    phase_start: Option<Instant>,
+}
+
+impl<'a> PhaseTimer<'a> {
+    fn start(mut timings: Option<&'a mut StepTimings>) -> Self {
+        let phase_start = timings.as_ref().map(|_| Instant::now());
+        if let Some(timings) = timings.as_deref_mut() {
+            timings.steps += 1;
+        }
+        PhaseTimer {
+            timings,
+            phase_start,
+        }
+    }
+
+    // ends the current phase, adding its time to the field returned by `phase`
+    fn lap(&mut self, phase: fn(&mut StepTimings) -> &mut Duration) {
+        if let (Some(timings), Some(phase_start)) = (self.timings.as_deref_mut(), self.phase_start)
+        {
+            let now = Instant::now();
+            *phase(timings) += now - phase_start;
+            self.phase_start = Some(now);
+        }
+    }
+}
+
+/// Advances the world by `dt` seconds.
+/// Each owner's last input in `inputs` is applied to all of their alive cells first.
+pub fn step<O: Copy + Eq + Hash + Ord>(
+    world: &mut World<O>,
+    inputs: &[Input<O>],
+    dt: f64,
+    rng: &mut impl Rng,
+) -> Vec<Event<O>> {
+    step_timed(world, inputs, dt, rng, None)
+}
+
+/// Like `step`, but adds the time spent in each phase to `timings`, if given.
+pub fn step_timed<O: Copy + Eq + Hash + Ord>(
+    world: &mut World<O>,
+    inputs: &[Input<O>],
+    dt: f64,
+    rng: &mut impl Rng,
+    timings: Option<&mut StepTimings>,
+) -> Vec<Event<O>> {
+    let mut timer = PhaseTimer::start(timings);
+    let mut events = Vec::new();
+
+    // Longer steps run the whole simulation in substeps, so a late tick plays out like several
+    // on-time ticks would. Inputs are applied at the start of the first one.
+    let substeps = (dt / MAX_SUBSTEP).ceil().max(1.0) as usize;
+    let start_micros = world.now_micros;
+    let step_micros = (dt * 1_000_000.0).round() as i64;
+    for substep in 0..substeps {
+        let inputs = if substep == 0 { inputs } else { &[] };
+        world.now_micros = start_micros + step_micros * (substep as i64 + 1) / substeps as i64;
+        simulate(
+            world,
+            inputs,
+            dt / substeps as f64,
+            rng,
+            &mut timer,
+            &mut events,
+        );
+    }
+    events
+}
+
+// Simulates a single substep of `dt` seconds, ending at `world.now_micros`.
+fn simulate<O: Copy + Eq + Hash + Ord>(
+    world: &mut World<O>,
+    inputs: &[Input<O>],
+    dt: f64,
+    rng: &mut impl Rng,
+    timer: &mut PhaseTimer,
+    events: &mut Vec<Event<O>>,
+) {
+    let config = &world.config;
+    let now_micros = world.now_micros;
+
+    let mut balls = std::mem::take(&mut world.balls);
+    // (food, keep) pairs
+    let mut foods = std::mem::take(&mut world.foods)
+        .into_iter()
+        .map(|f| (f, true))
+        .collect::<Vec<_>>();
+    // (virus, keep) pairs
+    let mut viruses = std::mem::take(&mut world.viruses)
+        .into_iter()
+        .map(|v| (v, true))
+        .collect::<Vec<_>>();
+    let mut virus_grid = SpatialHashGrid::new(GRID_CELL_SIZE);
+    for (idx, (virus, _)) in viruses.iter().enumerate() {
+        virus_grid.insert_with_aabb(idx, virus.get_aabb());
+    }
+
+    // Apply inputs
+    let inputs = inputs
+        .iter()
+        .map(|input| (input.owner, input))
+        .collect::<HashMap<_, _>>();
+    for ball in &mut balls {
+        if ball.dead {
+            continue;
+        }
+        let Some(input) = inputs.get(&ball.owner) else {
+            continue;
+        };
+        let mut impulse_x = input.impulse_x;
+        let mut impulse_y = input.impulse_y;
+        // cap impulse
+        let impulse = impulse_x.hypot(impulse_y);
+        if impulse > config.max_impulse {
+            let scale = config.max_impulse / impulse;
+            impulse_x *= scale;
+            impulse_y *= scale;
+        }
+        ball.apply_impulse(config, impulse_x, impulse_y);
+    }
+
+    timer.lap(|t| &mut t.inputs);
+
+    // where the balls start, to find what they passed on their way
+    let starts = balls.iter().map(|b| (b.x, b.y)).collect::<Vec<_>>();
+
+    let drag = config.drag_per_second.powf(dt);
+
+    // Update positions individually, sleeping balls stay where they are
+    for ball in &mut balls {
+        if ball.dead || !ball.is_moving() {
+            continue;
+        }
+        ball.vx *= drag;
+        ball.vy *= drag;
+
+        ball.x += ball.vx * dt;
+        ball.y += ball.vy * dt;
+    }
+
+    // Update wall collisions with WORLD_BORDER
+    for ball in &mut balls {
+        if ball.dead {
+            continue;
+        }
+        bounce_off_walls(config, ball);
+    }
+
+    // Put slow balls to sleep, so they don't change anymore until something pushes them
+    for ball in &mut balls {
+        if ball.vx.hypot(ball.vy) < SLEEP_VELOCITY {
+            ball.vx = 0.0;
+            ball.vy = 0.0;
+        }
+    }
+
+    // Balls that moved far compared to their size are tested along the line from where they
+    // started, so they can't tunnel through foods and other balls. Wall bounces make the path
+    // shorter than the distance the ball actually moved, which is fine for this.
+    let swept = balls
+        .iter()
+        .zip(&starts)
+        .map(|(ball, &(x, y))| {
+            !ball.dead && (ball.x - x).hypot(ball.y - y) > ball.radius * CCD_TRAVEL_RATIO
+        })
+        .collect::<Vec<_>>();
+    let path_aabb = |idx: usize, ball: &Ball<O>| {
+        if swept[idx] {
+            let (x, y) = starts[idx];
+            ball.get_aabb().union(Ball { x, y, ..*ball }.get_aabb())
+        } else {
+            ball.get_aabb()
+        }
+    };
+
+    timer.lap(|t| &mut t.movement);
+
+    // Update ejected pellets, they slow down like balls and bounce off the walls
+    for (food, _) in &mut foods {
+        if !food.is_moving() {
+            continue;
+        }
+
+        food.vx *= drag;
+        food.vy *= drag;
+        food.x += food.vx * dt;
+        food.y += food.vy * dt;
+
+        if food.x < config.world_border_min_x {
+            food.x = config.world_border_min_x;
+            food.vx = food.vx.abs() * config.restitution;
+        }
+        if food.x > config.world_border_max_x {
+            food.x = config.world_border_max_x;
+            food.vx = -food.vx.abs() * config.restitution;
+        }
+        if food.y < config.world_border_min_y {
+            food.y = config.world_border_min_y;
+            food.vy = food.vy.abs() * config.restitution;
+        }
+        if food.y > config.world_border_max_y {
+            food.y = config.world_border_max_y;
+            food.vy = -food.vy.abs() * config.restitution;
+        }
+
+        if (food.vx * food.vx + food.vy * food.vy).sqrt() < REST_VELOCITY {
+            // at rest, it's a regular food now that anyone can eat
+            food.vx = 0.0;
+            food.vy = 0.0;
+            food.owner = None;
+        }
+    }
+
+    timer.lap(|t| &mut t.pellets);
+
+    // Feed viruses with ejected pellets. A virus that has been fed enough spawns a new virus
+    // in the direction the pellet was flying.
+    let virus_count = viruses.len();
+    let mut new_viruses = Vec::new();
+    for (food, keep) in &mut foods {
+        if !*keep || !food.is_moving() {
+            continue;
+        }
+        for &virus_idx in virus_grid.get_for_aabb(food.get_aabb()) {
+            let (virus, _) = &mut viruses[virus_idx];
+            let dx = food.x - virus.x;
+            let dy = food.y - virus.y;
+            let distance = (dx * dx + dy * dy).sqrt();
+            if distance >= virus.radius {
+                continue;
+            }
+
+            // virus absorbs the pellet
+            *keep = false;
+            virus.fed += 1;
+            if virus.fed >= Virus::FEEDS_TO_SPAWN {
+                virus.fed = 0;
+                if virus_count + new_viruses.len() < Virus::MAX_VIRUSES {
+                    let speed = (food.vx * food.vx + food.vy * food.vy).sqrt();
+                    let x = virus.x + food.vx / speed * virus.radius * 2.0;
+                    let y = virus.y + food.vy / speed * virus.radius * 2.0;
+                    new_viruses.push(Virus::new_at(config, x, y));
+                    events.push(Event::VirusSpawned {
+                        parent_id: virus.id,
+                    });
+                }
+            }
+            break;
+        }
+    }
+
+    timer.lap(|t| &mut t.virus_feeding);
+
+    // Handle food eating. Foods rarely move, so few of them change their cell in the index.
+    let food_index = &mut world.food_index;
+    let mut food_id_to_idx = HashMap::with_capacity(foods.len());
+    for (idx, (food, keep)) in foods.iter().enumerate() {
+        if *keep {
+            food_index.set(food.id, food.get_aabb());
+            food_id_to_idx.insert(food.id, idx);
+        }
+    }
+    food_index.retain(|id| food_id_to_idx.contains_key(&id));
+
+    for (idx, ball) in balls.iter_mut().enumerate() {
+        if ball.dead {
+            continue;
+        }
+        for food_id in food_index.get_for_aabb(path_aabb(idx, ball)) {
+            let (food, keep) = &mut foods[food_id_to_idx[food_id]];
+            if !*keep {
+                continue;
+            }
+            if food.owner == Some(ball.owner) {
+                // can't eat our own pellets while they're still flying
+                continue;
+            }
+            let distance = if swept[idx] {
+                distance_to_segment((food.x, food.y), starts[idx], (ball.x, ball.y))
+            } else {
+                (ball.x - food.x).hypot(ball.y - food.y)
+            };
+            if distance < ball.radius {
+                // ball eats food
+                ball.add_mass(food.mass);
+                *keep = false;
+                events.push(Event::FoodEaten {
+                    id: food.id,
+                    eaten_by: ball.owner,
+                });
+            }
+        }
+    }
+
+    timer.lap(|t| &mut t.food_eating);
+
+    // Handle virus collisions. Balls smaller than a virus pass under it,
+    // balls big enough to cover a virus eat it and get popped into pieces.
+    let merge_at_micros = now_micros + MERGE_MICROS;
+    let mut cell_counts = HashMap::<O, usize>::new();
+    for ball in balls.iter().filter(|b| !b.dead) {
+        *cell_counts.entry(ball.owner).or_default() += 1;
+    }
+    let mut popped_pieces = Vec::new();
+    for ball in &mut balls {
+        if ball.dead {
+            continue;
+        }
+        for &virus_idx in virus_grid.get_for_aabb(ball.get_aabb()) {
+            let (virus, keep) = &mut viruses[virus_idx];
+            if !*keep || ball.radius < virus.radius * Virus::POP_RADIUS_RATIO {
+                continue;
+            }
+            let dx = ball.x - virus.x;
+            let dy = ball.y - virus.y;
+            let distance = (dx * dx + dy * dy).sqrt();
+            if distance >= ball.radius {
+                // the ball has to cover the virus' center
+                continue;
+            }
+
+            ball.add_mass(virus.mass());
+            *keep = false;
+            events.push(Event::VirusEaten {
+                id: virus.id,
+                eaten_by: ball.owner,
+            });
+            let cell_count = cell_counts.entry(ball.owner).or_default();
+            let free_cells = MAX_CELLS_PER_PLAYER.saturating_sub(*cell_count);
+            let pieces = ball.pop(
+                config,
+                (free_cells + 1).min(Virus::POP_PIECES),
+                merge_at_micros,
+            );
+            *cell_count += pieces.len();
+            if !pieces.is_empty() {
+                events.push(Event::BallPopped {
+                    owner: ball.owner,
+                    pieces: pieces.len() + 1,
+                });
+            }
+            popped_pieces.extend(pieces);
+        }
+    }
+
+    timer.lap(|t| &mut t.virus_pops);
+
+    // update collisions fast. only alive balls with an id are in the broadphase, which is kept
+    // from the last step so it only needs to learn about the changes.
+    if world.ball_broadphase.kind() != config.broadphase {
+        world.ball_broadphase = AnyBroadphase::new(config.broadphase);
+    }
+    let ball_broadphase = &mut world.ball_broadphase;
+    let mut id_to_idx = HashMap::with_capacity(balls.len());
+    for (idx, ball) in balls.iter().enumerate() {
+        if ball.dead || ball.id == 0 {
+            continue;
+        }
+        ball_broadphase.set(ball.id, path_aabb(idx, ball));
+        id_to_idx.insert(ball.id, idx);
+    }
+    ball_broadphase.retain(&mut |id| id_to_idx.contains_key(&id));
+
+    // cells that were dead before this step, i.e. are waiting to respawn
+    let already_dead = balls.iter().map(|b| b.dead).collect::<Vec<_>>();
+    let mut eaten_by = HashMap::<usize, O>::new();
+    // every touching pair is resolved once, in the order of their ids
+    ball_broadphase.for_each_candidate_pair(&mut |id1, id2| {
+        let idx1 = id_to_idx[id1];
+        let idx2 = id_to_idx[id2];
+        if balls[idx1].dead || balls[idx2].dead {
+            return;
+        }
+        let (ball1, ball2) = two_mut(&mut balls, idx1, idx2);
+        if swept[idx1] || swept[idx2] {
+            let starts = [starts[idx1], starts[idx2]];
+            handle_swept_collision(config, ball1, ball2, starts, dt, now_micros, rng);
+        } else {
+            handle_collision(config, ball1, ball2, now_micros, rng);
+        }
+        if ball1.dead {
+            eaten_by.insert(idx1, ball2.owner);
+        }
+        if ball2.dead {
+            eaten_by.insert(idx2, ball1.owner);
+        }
+    });
+
+    timer.lap(|t| &mut t.collisions);
+
+    // a player whose last cell got eaten keeps one dead cell around until it respawns,
+    // all other eaten cells are removed.
+    let alive_owners = balls
+        .iter()
+        .chain(&popped_pieces)
+        .filter(|b| !b.dead)
+        .map(|b| b.owner)
+        .collect::<HashSet<_>>();
+    let mut respawning_owners = HashSet::new();
+    for (idx, ball) in balls.into_iter().enumerate() {
+        if !ball.dead || already_dead[idx] {
+            world.balls.push(ball);
+            continue;
+        }
+        if let Some(&eater) = eaten_by.get(&idx) {
+            events.push(Event::BallEaten {
+                id: ball.id,
+                owner: ball.owner,
+                eaten_by: eater,
+            });
+        }
+        if !alive_owners.contains(&ball.owner) && respawning_owners.insert(ball.owner) {
+            events.push(Event::PlayerDied { owner: ball.owner });
+            world.balls.push(ball);
+        }
+    }
+    world.balls.extend(popped_pieces);
+
+    world.foods = foods
+        .into_iter()
+        .filter(|(_, keep)| *keep)
+        .map(|(food, _)| food)
+        .collect();
+    world.viruses = viruses
+        .into_iter()
+        .filter(|(_, keep)| *keep)
+        .map(|(virus, _)| virus)
+        .chain(new_viruses)
+        .collect();
+    timer.lap(|t| &mut t.cleanup);
+}
+
+/// Mutable references to two different elements of `slice`, in the given order.
+fn two_mut<T>(slice: &mut [T], idx1: usize, idx2: usize) -> (&mut T, &mut T) {
+    if idx1 < idx2 {
+        let (left, right) = slice.split_at_mut(idx2);
+        (&mut left[idx1], &mut right[0])
+    } else {
+        let (left, right) = slice.split_at_mut(idx1);
+        (&mut right[0], &mut left[idx2])
+    }
+}
+
+/// Keeps a ball inside the world borders, bouncing it off the walls it crossed.
+/// The ball always leaves the wall, even if a collision pushed it in while it moved away from it.
+fn bounce_off_walls<O>(config: &Config, ball: &mut Ball<O>) {
+    if ball.x - ball.radius < config.world_border_min_x {
+        ball.x = config.world_border_min_x + ball.radius;
+        ball.vx = ball.vx.abs() * config.restitution;
+    }
+    if ball.x + ball.radius > config.world_border_max_x {
+        ball.x = config.world_border_max_x - ball.radius;
+        ball.vx = -ball.vx.abs() * config.restitution;
+    }
+    if ball.y - ball.radius < config.world_border_min_y {
+        ball.y = config.world_border_min_y + ball.radius;
+        ball.vy = ball.vy.abs() * config.restitution;
+    }
+    if ball.y + ball.radius > config.world_border_max_y {
+        ball.y = config.world_border_max_y - ball.radius;
+        ball.vy = -ball.vy.abs() * config.restitution;
+    }
+}
+
+/// The distance of `point` to the line segment from `start` to `end`.
+fn distance_to_segment(point: (f64, f64), start: (f64, f64), end: (f64, f64)) -> f64 {
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    let length_squared = dx * dx + dy * dy;
+    let t = if length_squared == 0.0 {
+        0.0
+    } else {
+        (((point.0 - start.0) * dx + (point.1 - start.1) * dy) / length_squared).clamp(0.0, 1.0)
+    };
+    (start.0 + t * dx - point.0).hypot(start.1 + t * dy - point.1)
+}
+
+/// The fraction of the step at which two balls, moving in a straight line from their start to
+/// their current position, first touch. None if they don't touch during the step, or already
+/// touched at its start, which the discrete collision of the previous step took care of.
+fn time_of_impact<O>(
+    ball1: &Ball<O>,
+    ball2: &Ball<O>,
+    [start1, start2]: [(f64, f64); 2],
+) -> Option<f64> {
+    // the relative position at the start and the relative motion during the step
+    let (px, py) = (start1.0 - start2.0, start1.1 - start2.1);
+    let mx = (ball1.x - start1.0) - (ball2.x - start2.0);
+    let my = (ball1.y - start1.1) - (ball2.y - start2.1);
+    let radii = ball1.radius + ball2.radius;
+
+    // solve |p + m * t| = radii for the first t
+    let a = mx * mx + my * my;
+    let b = 2.0 * (px * mx + py * my);
+    let c = px * px + py * py - radii * radii;
+    if c <= 0.0 || b >= 0.0 {
+        return None;
+    }
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let t = (-b - discriminant.sqrt()) / (2.0 * a);
+    (t <= 1.0).then_some(t)
+}
+
+/// Like `handle_collision`, for balls that moved far in this step and may have passed through
+/// each other. Balls that touched on their way are rewound to where they touched, bounce there,
+/// and move on with their new velocities for the rest of the step.
+fn handle_swept_collision<O: Copy + PartialEq>(
+    config: &Config,
+    ball1: &mut Ball<O>,
+    ball2: &mut Ball<O>,
+    [start1, start2]: [(f64, f64); 2],
+    step_seconds: f64,
+    now_micros: i64,
+    rng: &mut impl Rng,
+) {
+    if (ball1.x - ball2.x).hypot(ball1.y - ball2.y) < ball1.radius + ball2.radius {
+        handle_collision(config, ball1, ball2, now_micros, rng);
+        return;
+    }
+    let Some(t) = time_of_impact(ball1, ball2, [start1, start2]) else {
+        return;
+    };
+    if eats(config, ball1, ball2, now_micros) {
+        // the eater doesn't stop, so it stays where it is
+        handle_eating(ball1, ball2);
+        return;
+    }
+
+    for (ball, start) in [(&mut *ball1, start1), (&mut *ball2, start2)] {
+        ball.x = start.0 + (ball.x - start.0) * t;
+        ball.y = start.1 + (ball.y - start.1) * t;
+    }
+    let radii = ball1.radius + ball2.radius;
+    let normal_x = (ball1.x - ball2.x) / radii;
+    let normal_y = (ball1.y - ball2.y) / radii;
+    bounce(config, ball1, ball2, normal_x, normal_y);
+
+    let remaining_seconds = (1.0 - t) * step_seconds;
+    for ball in [ball1, ball2] {
+        ball.x += ball.vx * remaining_seconds;
+        ball.y += ball.vy * remaining_seconds;
+        bounce_off_walls(config, ball);
+    }
+}
+
+/// Whether one of two touching cells eats the other one, instead of bouncing off it.
+/// Cells of the same owner merge instead, once both of their merge timers ran out.
+fn eats<O: PartialEq>(config: &Config, ball1: &Ball<O>, ball2: &Ball<O>, now_micros: i64) -> bool {
+    if ball1.owner == ball2.owner {
+        ball1.merge_at_micros <= now_micros && ball2.merge_at_micros <= now_micros
+    } else {
+        (ball1.radius - ball2.radius).abs() > config.delta_radius_required_for_eating
+    }
+}
+
+/// Lets the bigger of two cells eat the other one, or separates them if neither can eat the other.
+fn handle_collision<O: Copy + PartialEq>(
+    config: &Config,
+    ball1: &mut Ball<O>,
+    ball2: &mut Ball<O>,
+    now_micros: i64,
+    rng: &mut impl Rng,
+) {
+    let mut dx = ball1.x - ball2.x;
+    let mut dy = ball1.y - ball2.y;
+    // hypot doesn't underflow to 0 for balls that are very close but not on top of each other
+    let mut distance = dx.hypot(dy);
+    let overlap = ball1.radius + ball2.radius - distance;
+    if overlap <= 0.0 {
+        return;
+    }
+
+    if eats(config, ball1, ball2, now_micros) {
+        handle_eating(ball1, ball2);
+        return;
+    }
+
+    if distance == 0.0 {
+        // exactly on top of each other, separate in a random direction
+        let angle = rng.next_f64() * std::f64::consts::TAU;
+        (dy, dx) = angle.sin_cos();
+        distance = 1.0;
+    }
+
+    // the unit normal, pointing from ball2 to ball1
+    let normal_x = dx / distance;
+    let normal_y = dy / distance;
+
+    // push the balls apart along the normal until they just touch,
+    // weighted by inverse mass so the smaller ball moves more
+    let inv_mass1 = 1.0 / ball1.mass();
+    let inv_mass2 = 1.0 / ball2.mass();
+    let correction = overlap / (inv_mass1 + inv_mass2);
+    ball1.x += normal_x * correction * inv_mass1;
+    ball1.y += normal_y * correction * inv_mass1;
+    ball2.x -= normal_x * correction * inv_mass2;
+    ball2.y -= normal_y * correction * inv_mass2;
+
+    bounce(config, ball1, ball2, normal_x, normal_y);
+}
+
+/// Applies an impulse along the unit normal pointing from `ball2` to `ball1` that reverses the
+/// speed at which they approach each other, scaled by the restitution.
+/// Balls that already move apart keep their velocities.
+fn bounce<O: Copy + PartialEq>(
+    config: &Config,
+    ball1: &mut Ball<O>,
+    ball2: &mut Ball<O>,
+    normal_x: f64,
+    normal_y: f64,
+) {
+    let approach_speed = (ball1.vx - ball2.vx) * normal_x + (ball1.vy - ball2.vy) * normal_y;
+    if approach_speed >= 0.0 {
+        return;
+    }
+    let inv_mass1 = 1.0 / ball1.mass();
+    let inv_mass2 = 1.0 / ball2.mass();
+    let impulse = -(1.0 + config.restitution) * approach_speed / (inv_mass1 + inv_mass2);
+    ball1.vx += impulse * normal_x * inv_mass1;
+    ball1.vy += impulse * normal_y * inv_mass1;
+    ball2.vx -= impulse * normal_x * inv_mass2;
+    ball2.vy -= impulse * normal_y * inv_mass2;
+}
+
+fn handle_eating<'a, O: Copy + PartialEq>(mut ball1: &'a mut Ball<O>, mut ball2: &'a mut Ball<O>) {
+    let mut mass1 = ball1.mass();
+    let mut mass2 = ball2.mass();
+    if mass2 > mass1 {
+        (ball1, ball2) = (ball2, ball1);
+        (mass1, mass2) = (mass2, mass1);
+    }
+
+    // ball1 eats ball2
+    // whether ball2 gets respawned or removed is decided at the end of the step,
+    // depending on whether its owner has any cells left.
+    ball1.update_mass(mass1 + mass2);
+    ball2.dead = true;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Config, SplitMix64};
+    use proptest::prelude::*;
+
+    // relative to the magnitudes involved, the math is in f64
+    const EPSILON: f64 = 1e-9;
+
+    fn ball(owner: u8, x: f64, y: f64, vx: f64, vy: f64, radius: f64) -> Ball<u8> {
+        Ball {
+            id: owner as u64,
+            owner,
+            x,
+            y,
+            vx,
+            vy,
+            radius,
+            dead: false,
+            merge_at_micros: 0,
+        }
+    }
+
+    // radii of two balls that bounce off each other
+    fn similar_radii() -> impl Strategy<Value = (f64, f64)> {
+        let delta = Config::default().delta_radius_required_for_eating;
+        (0.5..200.0, -delta..=delta).prop_map(|(r1, d): (f64, f64)| (r1, (r1 + d).max(0.5)))
+    }
+
+    // radii of two balls where one eats the other
+    fn different_radii() -> impl Strategy<Value = (f64, f64)> {
+        let delta = Config::default().delta_radius_required_for_eating;
+        (0.5..200.0, delta + 0.01..200.0, any::<bool>()).prop_map(|(r1, d, swap)| {
+            if swap {
+                (r1 + d, r1)
+            } else {
+                (r1, r1 + d)
+            }
+        })
+    }
+
+    fn any_radii() -> impl Strategy<Value = (f64, f64)> {
+        (0.5..200.0, 0.5..200.0)
+    }
+
+    // two balls of different owners whose centers are at most `r1 + r2` apart
+    fn touching_balls(
+        radii: impl Strategy<Value = (f64, f64)>,
+    ) -> impl Strategy<Value = (Ball<u8>, Ball<u8>)> {
+        (
+            (-1000.0..1000.0, -1000.0..1000.0),
+            (-500.0..500.0, -500.0..500.0),
+            (-500.0..500.0, -500.0..500.0),
+            radii,
+            0.0..std::f64::consts::TAU,
+            0.0..=1.0,
+        )
+            .prop_map(
+                |((x, y), (vx1, vy1), (vx2, vy2), (r1, r2), angle, closeness)| {
+                    let distance = (r1 + r2) * (1.0 - closeness);
+                    let (sin, cos) = f64::sin_cos(angle);
+                    (
+                        ball(1, x, y, vx1, vy1, r1),
+                        ball(2, x + cos * distance, y + sin * distance, vx2, vy2, r2),
+                    )
+                },
+            )
+    }
+
+    fn collide(ball1: &mut Ball<u8>, ball2: &mut Ball<u8>, seed: u64) {
+        collide_with(&Config::default(), ball1, ball2, seed);
+    }
+
+    fn collide_with(config: &Config, ball1: &mut Ball<u8>, ball2: &mut Ball<u8>, seed: u64) {
+        handle_collision(config, ball1, ball2, 0, &mut SplitMix64(seed));
+    }
+
+    // the speed along the normal at which the balls approach each other, negative if they separate
+    fn approach_speed(ball1: &Ball<u8>, ball2: &Ball<u8>) -> f64 {
+        let (dx, dy) = (ball1.x - ball2.x, ball1.y - ball2.y);
+        let distance = dx.hypot(dy);
+        ((ball2.vx - ball1.vx) * dx + (ball2.vy - ball1.vy) * dy) / distance
+    }
+
+    fn momentum(balls: [&Ball<u8>; 2]) -> (f64, f64) {
+        balls.iter().fold((0.0, 0.0), |(px, py), ball| {
+            (px + ball.mass() * ball.vx, py + ball.mass() * ball.vy)
+        })
+    }
+
+    fn assert_finite(ball: &Ball<u8>) {
+        for value in [ball.x, ball.y, ball.vx, ball.vy, ball.radius] {
+            assert!(value.is_finite(), "{ball:?}");
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn outputs_are_finite((mut ball1, mut ball2) in touching_balls(any_radii()), seed: u64) {
+            collide(&mut ball1, &mut ball2, seed);
+            assert_finite(&ball1);
+            assert_finite(&ball2);
+        }
+
+        #[test]
+        fn bounces_conserve_momentum((mut ball1, mut ball2) in touching_balls(similar_radii()), seed: u64) {
+            let (px, py) = momentum([&ball1, &ball2]);
+            collide(&mut ball1, &mut ball2, seed);
+            let (new_px, new_py) = momentum([&ball1, &ball2]);
+            let scale = 1.0 + px.abs() + py.abs();
+            prop_assert!((new_px - px).abs() <= EPSILON * scale, "{px} -> {new_px}");
+            prop_assert!((new_py - py).abs() <= EPSILON * scale, "{py} -> {new_py}");
+        }
+
+        #[test]
+        fn bounces_leave_no_overlap((mut ball1, mut ball2) in touching_balls(similar_radii()), seed: u64) {
+            collide(&mut ball1, &mut ball2, seed);
+            let distance = (ball1.x - ball2.x).hypot(ball1.y - ball2.y);
+            let radii = ball1.radius + ball2.radius;
+            prop_assert!(distance >= radii - EPSILON * (1.0 + radii), "{distance} < {radii}");
+        }
+
+        #[test]
+        fn bounces_separate((mut ball1, mut ball2) in touching_balls(similar_radii()), seed: u64) {
+            collide(&mut ball1, &mut ball2, seed);
+            // the balls don't move towards each other afterwards
+            let (dx, dy) = (ball1.x - ball2.x, ball1.y - ball2.y);
+            let approach = (ball1.vx - ball2.vx) * dx + (ball1.vy - ball2.vy) * dy;
+            let speed = ball1.vx.abs() + ball1.vy.abs() + ball2.vx.abs() + ball2.vy.abs();
+            prop_assert!(approach >= -EPSILON * (1.0 + speed) * dx.hypot(dy), "{approach}");
+        }
+
+        #[test]
+        fn restitution_scales_the_bounce(
+            (mut ball1, mut ball2) in touching_balls(similar_radii()),
+            restitution in 0.0..=1.0,
+            seed: u64,
+        ) {
+            // coincident balls get a random normal
+            prop_assume!(ball1.x != ball2.x || ball1.y != ball2.y);
+            if approach_speed(&ball1, &ball2) < 0.0 {
+                // reverse time so they approach each other
+                for ball in [&mut ball1, &mut ball2] {
+                    (ball.vx, ball.vy) = (-ball.vx, -ball.vy);
+                }
+            }
+            let before = approach_speed(&ball1, &ball2);
+            prop_assume!(before > 0.0);
+            let config = Config {
+                restitution,
+                ..Config::default()
+            };
+            collide_with(&config, &mut ball1, &mut ball2, seed);
+            let after = approach_speed(&ball1, &ball2);
+            prop_assert!((after + restitution * before).abs() <= EPSILON * (1.0 + before), "{before} -> {after}");
+        }
+
+        #[test]
+        fn smaller_ball_moves_more((mut ball1, mut ball2) in touching_balls(similar_radii()), seed: u64) {
+            let (x1, y1, x2, y2) = (ball1.x, ball1.y, ball2.x, ball2.y);
+            collide(&mut ball1, &mut ball2, seed);
+            let moved1 = (ball1.x - x1).hypot(ball1.y - y1);
+            let moved2 = (ball2.x - x2).hypot(ball2.y - y2);
+            // both moved by the overlap, split in inverse proportion to their masses
+            let tolerance = EPSILON * (1.0 + moved1 + moved2);
+            prop_assert!((moved1 * ball1.mass() - moved2 * ball2.mass()).abs() <= tolerance * (ball1.mass() + ball2.mass()));
+        }
+
+        #[test]
+        fn eating_conserves_mass((mut ball1, mut ball2) in touching_balls(different_radii()), seed: u64) {
+            let mass = ball1.mass() + ball2.mass();
+            collide(&mut ball1, &mut ball2, seed);
+            let (eater, eaten) = if ball1.dead { (&ball2, &ball1) } else { (&ball1, &ball2) };
+            prop_assert!(eaten.dead && !eater.dead);
+            prop_assert!((eater.mass() - mass).abs() <= EPSILON * mass);
+        }
+
+        #[test]
+        fn coincident_balls_separate(
+            (x, y) in (-1000.0..1000.0, -1000.0..1000.0),
+            radius in 0.5..200.0,
+            (vx, vy) in (-500.0..500.0, -500.0..500.0),
+            seed: u64,
+        ) {
+            let mut ball1 = ball(1, x, y, vx, vy, radius);
+            let mut ball2 = ball(2, x, y, -vx, -vy, radius);
+            collide(&mut ball1, &mut ball2, seed);
+            assert_finite(&ball1);
+            assert_finite(&ball2);
+            let distance = (ball1.x - ball2.x).hypot(ball1.y - ball2.y);
+            prop_assert!(distance >= 2.0 * radius * (1.0 - EPSILON));
+            let (px, py) = momentum([&ball1, &ball2]);
+            prop_assert!(px.abs() + py.abs() <= EPSILON * (1.0 + ball1.mass() * (vx.abs() + vy.abs())));
+        }
+    }
+
+    fn world(balls: Vec<Ball<u8>>) -> World<u8> {
+        let mut world = World::new(Config::default(), 0);
+        world.balls = balls;
+        world
+    }
+
+    // the fast balls in these tests move about 50 units in a step, much more than the balls and
+    // foods in their way are wide
+
+    #[test]
+    fn fast_ball_bounces_off_ball_in_its_path() {
+        let mut world = world(vec![
+            ball(1, -20.0, 0.0, 3000.0, 0.0, 4.0),
+            ball(2, 0.0, 0.0, 0.0, 0.0, 4.0),
+        ]);
+        step(&mut world, &[], MAX_SUBSTEP, &mut SplitMix64(0));
+        let (fast, hit) = (&world.balls[0], &world.balls[1]);
+        assert!(fast.x < hit.x, "{fast:?} tunneled through {hit:?}");
+        assert!(hit.vx > 0.0);
+    }
+
+    #[test]
+    fn fast_ball_eats_food_in_its_path() {
+        let mut world = world(vec![ball(1, -20.0, 0.0, 3000.0, 0.0, 4.0)]);
+        world.foods.push(crate::Food {
+            id: 1,
+            x: 0.0,
+            y: 1.0,
+            vx: 0.0,
+            vy: 0.0,
+            mass: 3.0,
+            owner: None,
+        });
+        let events = step(&mut world, &[], MAX_SUBSTEP, &mut SplitMix64(0));
+        assert_eq!(events, vec![Event::FoodEaten { id: 1, eaten_by: 1 }]);
+        assert!(world.foods.is_empty());
+    }
+
+    #[test]
+    fn fast_ball_eats_ball_in_its_path() {
+        let mut world = world(vec![
+            ball(1, -40.0, 0.0, 3000.0, 0.0, 8.0),
+            ball(2, -15.0, 0.0, 0.0, 0.0, 4.0),
+        ]);
+        let events = step(&mut world, &[], MAX_SUBSTEP, &mut SplitMix64(0));
+        assert!(events.contains(&Event::BallEaten {
+            id: 2,
+            owner: 2,
+            eaten_by: 1,
+        }));
+        assert!(world.balls[0].x > -15.0, "the eater keeps moving");
+    }
+
+    #[test]
+    fn long_step_plays_like_several_short_ones() {
+        let start = || {
+            let mut world = world(vec![
+                ball(1, -60.0, 0.0, 600.0, 0.0, 8.0),
+                ball(2, 30.0, -28.0, 0.0, 0.0, 4.0),
+                ball(3, 0.0, 40.0, 0.0, -300.0, 8.0),
+            ]);
+            for (id, x) in [(1, -40.0), (2, -20.0), (3, 20.0)] {
+                world.foods.push(crate::Food {
+                    id,
+                    x,
+                    y: 0.0,
+                    vx: 0.0,
+                    vy: 0.0,
+                    mass: 3.0,
+                    owner: None,
+                });
+            }
+            world
+        };
+
+        let mut long = start();
+        let long_events = step(&mut long, &[], 0.25, &mut SplitMix64(0));
+        let mut short = start();
+        let mut short_events = Vec::new();
+        let mut rng = SplitMix64(0);
+        for _ in 0..5 {
+            short_events.extend(step(&mut short, &[], 0.05, &mut rng));
+        }
+
+        assert!(long_events.contains(&Event::BallEaten {
+            id: 2,
+            owner: 2,
+            eaten_by: 1,
+        }));
+        assert!(
+            long.balls[0].vy < 0.0,
+            "ball 1 bounced off ball 3 on its way"
+        );
+        assert_eq!(long_events, short_events);
+        assert_eq!(long.now_micros, short.now_micros);
+        assert_eq!(long.balls.len(), short.balls.len());
+        for (long, short) in long.balls.iter().zip(&short.balls) {
+            assert!((long.x - short.x).abs() < 1e-6 && (long.y - short.y).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn slow_ball_falls_asleep() {
+        let mut world = world(vec![ball(1, 0.0, 0.0, 20.0, 0.0, 4.0)]);
+        let mut rng = SplitMix64(0);
+        for _ in 0..120 {
+            step(&mut world, &[], MAX_SUBSTEP, &mut rng);
+        }
+        let asleep = world.balls[0].clone();
+        assert!(!asleep.is_moving());
+
+        step(&mut world, &[], MAX_SUBSTEP, &mut rng);
+        assert_eq!(world.balls[0], asleep, "sleeping balls don't change");
+    }
+
+    #[test]
+    fn sleeping_ball_wakes_up_when_hit() {
+        let mut world = world(vec![
+            ball(1, -10.0, 0.0, 300.0, 0.0, 4.0),
+            ball(2, 0.0, 0.0, 0.0, 0.0, 4.0),
+        ]);
+        let mut rng = SplitMix64(0);
+        for _ in 0..5 {
+            step(&mut world, &[], MAX_SUBSTEP, &mut rng);
+        }
+        assert!(world.balls[1].is_moving());
+        assert!(world.balls[1].x > 0.0);
+    }
+
+    #[test]
+    fn impulse_on_ball_smaller_than_default_radius_is_finite() {
+        let config = Config {
+            default_radius: 20.0,
+            ..Config::default()
+        };
+        let mut small = ball(1, 0.0, 0.0, 0.0, 0.0, 4.0);
+        small.apply_impulse(&config, config.max_impulse, 0.0);
+        assert_finite(&small);
+        assert!(small.vx > 0.0);
+    }
+
+    #[test]
+    fn huge_input_is_capped_without_losing_its_direction() {
+        let mut world = world(vec![ball(1, 0.0, 0.0, 0.0, 0.0, 4.0)]);
+        // the squares of these overflow
+        let input = Input {
+            owner: 1,
+            impulse_x: 1e200,
+            impulse_y: 1e200,
+        };
+        step(&mut world, &[input], 0.001, &mut SplitMix64(0));
+        let ball = &world.balls[0];
+        assert_finite(ball);
+        assert!(ball.vx > 0.0);
+        assert_eq!(ball.vx, ball.vy);
+    }
+
+    #[test]
+    fn merging_cells_conserve_mass() {
+        let mut ball1 = ball(1, 0.0, 0.0, 10.0, 0.0, 10.0);
+        let mut ball2 = Ball {
+            id: 2,
+            ..ball(1, 5.0, 0.0, 0.0, 0.0, 9.0)
+        };
+        let mass = ball1.mass() + ball2.mass();
+        collide(&mut ball1, &mut ball2, 0);
+        assert!(ball2.dead && !ball1.dead);
+        assert!((ball1.mass() - mass).abs() <= EPSILON * mass);
+    }
+}
@@ -0,0 +1,160 @@
+//! Synthetic worlds to benchmark the simulation without a SpacetimeDB host.
+
+use crate::{Ball, Config, Food, Input, Rng, SplitMix64, World};
+use std::str::FromStr;
+
+/// How ball sizes are distributed in a synthetic world.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Masses {
+    /// A fresh server, everyone about the spawn size.
+    Uniform,
+    /// A running server, a few players are much bigger than most.
+    HeavyTail,
+    /// A handful of balls covering a big part of the world.
+    Giants,
+}
+
+impl Masses {
+    pub const ALL: [Masses; 3] = [Masses::Uniform, Masses::HeavyTail, Masses::Giants];
+
+    // the biggest balls are this many times the default radius
+    const MAX_RADIUS_FACTOR: f64 = 40.0;
+    const GIANTS: usize = 20;
+
+    fn radius(self, config: &Config, idx: usize, rng: &mut impl Rng) -> f64 {
+        let max_radius = config.default_radius * Self::MAX_RADIUS_FACTOR;
+        match self {
+            Masses::Uniform => config.default_radius + rng.next_f64() * 2.0,
+            // pareto distributed with shape 1.5
+            Masses::HeavyTail => {
+                (config.default_radius * (1.0 - rng.next_f64()).powf(-1.0 / 1.5)).min(max_radius)
+            }
+            Masses::Giants if idx < Self::GIANTS => max_radius,
+            Masses::Giants => config.default_radius + rng.next_f64() * 2.0,
+        }
+    }
+}
+
+impl FromStr for Masses {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "uniform" => Ok(Masses::Uniform),
+            "heavy-tail" => Ok(Masses::HeavyTail),
+            "giants" => Ok(Masses::Giants),
+            _ => Err(format!(
+                "unknown mass distribution {s:?}, expected uniform, heavy-tail or giants"
+            )),
+        }
+    }
+}
+
+/// Describes a synthetic world. Every ball belongs to its own player.
+#[derive(Clone, Debug)]
+pub struct WorldSpec {
+    pub balls: usize,
+    pub foods: usize,
+    pub masses: Masses,
+    pub seed: u64,
+    // the world borders are chosen so that every ball has this much room on average
+    pub area_per_ball: f64,
+    pub config: Config,
+}
+
+impl Default for WorldSpec {
+    fn default() -> Self {
+        WorldSpec {
+            balls: 4000,
+            foods: 1000,
+            masses: Masses::Uniform,
+            seed: 42,
+            area_per_ball: 1000.0,
+            config: Config::default(),
+        }
+    }
+}
+
+impl WorldSpec {
+    pub fn build(&self) -> World<u64> {
+        let half_size = (self.balls as f64 * self.area_per_ball).sqrt() / 2.0;
+        let config = Config {
+            world_border_min_x: -half_size,
+            world_border_max_x: half_size,
+            world_border_min_y: -half_size,
+            world_border_max_y: half_size,
+            ..self.config.clone()
+        };
+        let mut rng = SplitMix64(self.seed);
+        let mut world = World::new(config.clone(), 0);
+        for idx in 0..self.balls {
+            let radius = self.masses.radius(&config, idx, &mut rng);
+            world
+                .balls
+                .push(spawn_ball(&config, idx as u64 + 1, radius, &mut rng));
+        }
+        for idx in 0..self.foods {
+            world
+                .foods
+                .push(spawn_food(&config, idx as u64 + 1, &mut rng));
+        }
+        world
+    }
+
+    /// Respawns eaten players and tops the food back up, like the server does over time,
+    /// so the world doesn't empty out over a long run.
+    pub fn replenish(&self, world: &mut World<u64>, rng: &mut impl Rng) {
+        let config = world.config.clone();
+        for ball in world.balls.iter_mut().filter(|ball| ball.dead) {
+            *ball = spawn_ball(&config, ball.id, config.default_radius, rng);
+        }
+        let mut next_food_id = world.foods.iter().map(|food| food.id).max().unwrap_or(0) + 1;
+        while world.foods.len() < self.foods {
+            world.foods.push(spawn_food(&config, next_food_id, rng));
+            next_food_id += 1;
+        }
+    }
+}
+
+fn spawn_ball(config: &Config, id: u64, radius: f64, rng: &mut impl Rng) -> Ball<u64> {
+    let (x, y) = config.random_pos_in_game_field(rng);
+    Ball {
+        id,
+        owner: id,
+        x,
+        y,
+        vx: (rng.next_f64() - 0.5) * 200.0,
+        vy: (rng.next_f64() - 0.5) * 200.0,
+        radius,
+        dead: false,
+        merge_at_micros: 0,
+    }
+}
+
+fn spawn_food(config: &Config, id: u64, rng: &mut impl Rng) -> Food<u64> {
+    let (x, y) = config.random_pos_in_game_field(rng);
+    Food {
+        id,
+        x,
+        y,
+        vx: 0.0,
+        vy: 0.0,
+        mass: 3.0,
+        owner: None,
+    }
+}
+
+/// Random inputs for about a tenth of the players, like a server where most players hold still.
+pub fn inputs(world: &World<u64>, rng: &mut impl Rng) -> Vec<Input<u64>> {
+    let mut inputs = Vec::new();
+    for ball in &world.balls {
+        if rng.next_f64() < 0.1 {
+            inputs.push(Input {
+                owner: ball.owner,
+                impulse_x: (rng.next_f64() - 0.5) * world.config.max_impulse,
+                impulse_y: (rng.next_f64() - 0.5) * world.config.max_impulse,
+            });
+        }
+    }
+    inputs
+}
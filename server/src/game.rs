@@ -0,0 +1,375 @@
+//! The game logic behind the reducers. It only talks to a `GameStore`, so it runs on the module's
+//! tables as well as in native tests. Authorization and rate limiting stay in the reducers.
+
+use crate::store::GameStore;
+use crate::{Ball, Food, PendingInput, Player, Rgb, Virus};
+use spacetimedb::{Identity, TimeDuration};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use tagars_physics as physics;
+
+// a tick never simulates more than this, so the world doesn't jump after the module was paused
+const MAX_TICK_DELTA_MICROS: i64 = 250_000;
+// Balls that changed less than this are not written back. Every update is sent to every
+// client, and sleeping balls don't change at all, so idle worlds don't cause any traffic.
+// Clients take balls without an update as unchanged as of the latest physics tick.
+const BALL_WRITE_EPSILON: f64 = 1e-3;
+
+thread_local! {
+    // The broadphase of the balls and the spatial index of the foods, kept in module memory across
+    // ticks so they don't have to be rebuilt every tick. They aren't part of the database, so they
+    // may be lost or be out of date after a rolled back transaction, but `physics::step` brings
+    // them in sync with the balls and foods it is given.
+    static BALL_BROADPHASE: RefCell<Option<physics::AnyBroadphase<u64>>> = const { RefCell::new(None) };
+    static FOOD_INDEX: RefCell<Option<physics::SpatialIndex<u64>>> = const { RefCell::new(None) };
+}
+
+/// Adds a player to the world with a fresh cell. Returning players keep their profile.
+pub fn connect(store: &mut impl GameStore, identity: Identity) {
+    if let Some(player) = store.player(identity) {
+        // clients start counting their inputs from 1 on every connection
+        store.update_player(Player {
+            online: true,
+            last_input_seq: 0,
+            ..player
+        });
+    } else {
+        let color = Rgb::random(store);
+        store.insert_player(Player {
+            identity,
+            name: Player::default_name(identity),
+            color,
+            joined_at: store.now(),
+            online: true,
+            last_input_seq: 0,
+        });
+    }
+
+    let config = store.config();
+    let ball = Ball::spawn_ball(store, &config, identity);
+    store.insert_ball(ball);
+}
+
+/// Removes all cells and the queued input of a player and marks them offline.
+/// Their name is released, so offline players can't hold on to names.
+pub fn disconnect(store: &mut impl GameStore, identity: Identity) {
+    remove_cells(store, identity);
+    store.delete_pending_input(identity);
+
+    if let Some(player) = store.player(identity) {
+        store.update_player(Player {
+            name: Player::default_name(identity),
+            online: false,
+            ..player
+        });
+    }
+}
+
+/// Removes all cells of a player from the world.
+pub fn remove_cells(store: &mut impl GameStore, identity: Identity) {
+    for ball in store.balls_of(identity) {
+        store.delete_ball(ball.id);
+    }
+}
+
+/// Queues an impulse for each of the cells of `owner`, which the next tick applies.
+/// Only the input with the highest `seq` of a tick is applied, older ones are dropped.
+pub fn queue_input(
+    store: &mut impl GameStore,
+    owner: Identity,
+    seq: u64,
+    impulse_x: f64,
+    impulse_y: f64,
+) {
+    let last_applied_seq = store.player(owner).map_or(0, |p| p.last_input_seq);
+    let pending = store.pending_input(owner);
+    let latest_seq = pending.as_ref().map_or(last_applied_seq, |p| p.seq);
+    if seq <= latest_seq {
+        // arrived out of order
+        return;
+    }
+
+    // the physics tick caps the impulse
+    let input = PendingInput {
+        owner,
+        seq,
+        impulse_x,
+        impulse_y,
+    };
+    if pending.is_some() {
+        store.update_pending_input(input);
+    } else {
+        store.insert_pending_input(input);
+    }
+}
+
+/// Simulates the world from the previous tick up to now and writes back what changed.
+pub fn tick(store: &mut impl GameStore) {
+    let now = store.now();
+    let config = store.config();
+
+    // the scheduler may run late, so integrate over the real time since the previous tick.
+    // the very first tick uses the configured tick length
+    let delta_micros = match store.latest_tick() {
+        Some(previous_tick) => {
+            now.to_micros_since_unix_epoch() - previous_tick.to_micros_since_unix_epoch()
+        }
+        None => config.tick_micros,
+    }
+    .clamp(0, MAX_TICK_DELTA_MICROS);
+
+    store.insert_tick(now);
+    // delete ticks older than 1 second
+    store.delete_ticks_before(now + TimeDuration::from_micros(-1_000_000));
+
+    let balls = store
+        .balls()
+        .into_iter()
+        .filter(|b| !b.dead)
+        .collect::<Vec<_>>();
+    let foods = store.foods();
+    let viruses = store.viruses();
+
+    // colors aren't simulated, new cells get the color of their owner
+    let ball_colors = balls
+        .iter()
+        .map(|b| (b.owner, b.color))
+        .collect::<HashMap<_, _>>();
+    let food_colors = foods
+        .iter()
+        .map(|f| (f.id, f.color))
+        .collect::<HashMap<_, _>>();
+    // remember what we loaded, so we only write rows that changed
+    let old_balls = balls
+        .iter()
+        .map(|b| (b.id, b.to_physics()))
+        .collect::<HashMap<_, _>>();
+    let old_foods = foods
+        .iter()
+        .map(|f| (f.id, f.to_physics()))
+        .collect::<HashMap<_, _>>();
+    let old_viruses = viruses
+        .iter()
+        .map(|v| (v.id, v.to_physics()))
+        .collect::<HashMap<_, _>>();
+
+    let mut world = physics::World {
+        config: config.physics(),
+        now_micros: now.to_micros_since_unix_epoch() - delta_micros,
+        balls: balls.iter().map(|b| b.to_physics()).collect(),
+        foods: foods.iter().map(|f| f.to_physics()).collect(),
+        viruses: viruses.iter().map(|v| v.to_physics()).collect(),
+        ball_broadphase: BALL_BROADPHASE
+            .take()
+            .unwrap_or_else(|| physics::AnyBroadphase::new(config.physics().broadphase)),
+        food_index: FOOD_INDEX
+            .take()
+            .unwrap_or_else(|| physics::SpatialIndex::new(physics::GRID_CELL_SIZE)),
+    };
+
+    // Consume queued inputs, in a fixed order so ticks don't depend on reducer arrival order
+    let mut inputs = store.pending_inputs();
+    inputs.sort_by_key(|input| input.owner);
+    for input in &inputs {
+        store.delete_pending_input(input.owner);
+        if let Some(player) = store.player(input.owner) {
+            store.update_player(Player {
+                last_input_seq: input.seq,
+                ..player
+            });
+        }
+    }
+    let inputs = inputs
+        .into_iter()
+        .map(|input| physics::Input {
+            owner: input.owner,
+            impulse_x: input.impulse_x,
+            impulse_y: input.impulse_y,
+        })
+        .collect::<Vec<_>>();
+
+    let dt = delta_micros as f64 / 1_000_000.0;
+    let events = physics::step(&mut world, &inputs, dt, store);
+    BALL_BROADPHASE.set(Some(world.ball_broadphase));
+    FOOD_INDEX.set(Some(world.food_index));
+
+    for event in events {
+        if let physics::Event::PlayerDied { owner } = event {
+            store.schedule_respawn(
+                owner,
+                now + TimeDuration::from_micros(config.respawn_micros),
+            );
+        }
+    }
+
+    // write back the simulated world
+    let mut remaining_balls = HashSet::new();
+    for ball in world.balls {
+        let color = ball_colors[&ball.owner];
+        if ball.id == 0 {
+            store.insert_ball(Ball::from_physics(ball, color));
+            continue;
+        }
+        remaining_balls.insert(ball.id);
+        if ball_changed(&old_balls[&ball.id], &ball) {
+            store.update_ball(Ball::from_physics(ball, color));
+        }
+    }
+    for id in old_balls.keys() {
+        if !remaining_balls.contains(id) {
+            store.delete_ball(*id);
+        }
+    }
+
+    let mut remaining_foods = HashSet::new();
+    for food in world.foods {
+        remaining_foods.insert(food.id);
+        if old_foods[&food.id] != food {
+            let color = food_colors[&food.id];
+            store.update_food(Food::from_physics(food, color));
+        }
+    }
+    for id in old_foods.keys() {
+        if !remaining_foods.contains(id) {
+            store.delete_food(*id);
+        }
+    }
+
+    let mut remaining_viruses = HashSet::new();
+    for virus in world.viruses {
+        if virus.id == 0 {
+            store.insert_virus(Virus::from_physics(virus));
+            continue;
+        }
+        remaining_viruses.insert(virus.id);
+        if old_viruses[&virus.id] != virus {
+            store.update_virus(Virus::from_physics(virus));
+        }
+    }
+    for id in old_viruses.keys() {
+        if !remaining_viruses.contains(id) {
+            store.delete_virus(*id);
+        }
+    }
+}
+
+// whether `new` differs from `old` by more than `BALL_WRITE_EPSILON`
+fn ball_changed(old: &physics::Ball<Identity>, new: &physics::Ball<Identity>) -> bool {
+    let moved = [
+        (old.x, new.x),
+        (old.y, new.y),
+        (old.vx, new.vx),
+        (old.vy, new.vy),
+        (old.radius, new.radius),
+    ]
+    .into_iter()
+    .any(|(old, new)| (new - old).abs() > BALL_WRITE_EPSILON);
+    moved || old.dead != new.dead || old.merge_at_micros != new.merge_at_micros
+}
+
+/// Replaces the dead cell of `owner` with a new ball. Does nothing if the player disconnected
+/// or has no dead cell anymore, e.g. because they rejoined in the meantime.
+pub fn respawn_ball(store: &mut impl GameStore, owner: Identity) {
+    let Some(dead_ball) = store.balls_of(owner).into_iter().find(|b| b.dead) else {
+        // player disconnected or is alive again, no need to respawn
+        return;
+    };
+
+    let config = store.config();
+    let ball = Ball {
+        id: dead_ball.id,
+        ..Ball::spawn_ball(store, &config, owner)
+    };
+    store.update_ball(ball);
+}
+
+/// Replaces popped viruses and tops up the foods.
+pub fn spawn_food(store: &mut impl GameStore) {
+    let config = store.config();
+
+    // Replace popped viruses
+    if store.virus_count() < Virus::MIN_VIRUSES {
+        let virus = Virus::spawn_virus(store, &config);
+        store.insert_virus(virus);
+    }
+
+    // Spawn a new food, but only if foods are not saturated yet
+    if store.food_count() >= config.max_foods {
+        return;
+    }
+
+    for _ in 0..100 {
+        let (x, y) = config.random_pos_in_game_field(store);
+        let food = Food {
+            id: 0,
+            x,
+            y,
+            vx: 0.0,
+            vy: 0.0,
+            mass: config.food_mass,
+            color: Rgb::random(store),
+            owner: None,
+        };
+        store.insert_food(food);
+    }
+}
+
+/// Splits each cell of `owner` that is big enough into two halves,
+/// launching the new half in the aim direction.
+pub fn split(store: &mut impl GameStore, owner: Identity, aim_x: f64, aim_y: f64) {
+    let aim = aim_x.hypot(aim_y);
+    if aim == 0.0 {
+        return;
+    }
+    let dir_x = aim_x / aim;
+    let dir_y = aim_y / aim;
+
+    let mut cells = store
+        .balls_of(owner)
+        .into_iter()
+        .filter(|b| !b.dead)
+        .collect::<Vec<_>>();
+    // split the biggest cells first in case we hit the cell limit
+    cells.sort_by(|a, b| b.radius.total_cmp(&a.radius));
+
+    let physics_config = store.config().physics();
+    let merge_at_micros = store.now().to_micros_since_unix_epoch() + physics::MERGE_MICROS;
+    let mut cell_count = cells.len();
+    for cell in cells {
+        if cell_count >= physics::MAX_CELLS_PER_PLAYER {
+            break;
+        }
+        if cell.radius < physics_config.min_split_radius() {
+            continue;
+        }
+
+        let mut half = cell.to_physics();
+        let piece = half.split(dir_x, dir_y, merge_at_micros);
+        store.update_ball(Ball::from_physics(half, cell.color));
+        store.insert_ball(Ball::from_physics(piece, cell.color));
+        cell_count += 1;
+    }
+}
+
+/// Ejects a pellet of mass from each cell of `owner` in the aim direction.
+/// The pellet slows down over time and can be eaten by anyone but `owner` until it comes to rest.
+pub fn eject_mass(store: &mut impl GameStore, owner: Identity, aim_x: f64, aim_y: f64) {
+    let aim = aim_x.hypot(aim_y);
+    if aim == 0.0 {
+        return;
+    }
+    let dir_x = aim_x / aim;
+    let dir_y = aim_y / aim;
+
+    let default_mass = store.config().physics().default_mass();
+    for cell in store.balls_of(owner) {
+        if cell.dead || cell.mass() - physics::EJECT_MASS < default_mass {
+            continue;
+        }
+
+        let mut rest = cell.to_physics();
+        let pellet = rest.eject_mass(dir_x, dir_y);
+        store.update_ball(Ball::from_physics(rest, cell.color));
+        store.insert_food(Food::from_physics(pellet, cell.color));
+    }
+}
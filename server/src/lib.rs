@@ -1,9 +1,12 @@
-mod spatial_hash_grid;
-
-use crate::spatial_hash_grid::{Aabb, SpatialHashGrid, SpatialHashable};
 use spacetimedb::{
     Identity, ReducerContext, ScheduleAt, SpacetimeType, Table, TimeDuration, Timestamp,
 };
+use std::collections::{HashMap, HashSet};
+use store::{CtxStore, GameStore};
+use tagars_physics as physics;
+
+pub mod game;
+pub mod store;
 
 #[spacetimedb::table(name = spawn_foods_schedule, scheduled(spawn_food))]
 struct SpawnFoodSchedule {
@@ -23,6 +26,15 @@ struct UpdateBallsSchedule {
     scheduled_at: ScheduleAt,
 }
 
+#[spacetimedb::table(name = update_leaderboard_schedule, scheduled(update_leaderboard))]
+struct UpdateLeaderboardSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+
+    scheduled_at: ScheduleAt,
+}
+
 #[spacetimedb::table(name = respawn_balls_schedule, scheduled(respawn_ball))]
 struct RespawnBallsSchedule {
     #[primary_key]
@@ -42,6 +54,317 @@ struct PhysicsTick {
     ticked_at: Timestamp,
 }
 
+/// Every connected player ranked by their total mass.
+/// Clients only need to subscribe to the top `LeaderboardEntry::TOP_N` ranks and their own row.
+#[spacetimedb::table(name = leaderboard, public)]
+pub struct LeaderboardEntry {
+    #[primary_key]
+    pub owner: Identity,
+    #[index(btree)]
+    pub rank: u32,
+    pub score: u64,
+}
+
+impl LeaderboardEntry {
+    pub const TOP_N: u32 = physics::LEADERBOARD_TOP_N;
+}
+
+/// The latest input of each player, applied by the next physics tick.
+#[derive(Clone)]
+#[spacetimedb::table(name = pending_inputs)]
+pub struct PendingInput {
+    #[primary_key]
+    pub owner: Identity,
+    pub seq: u64,
+    pub impulse_x: f64,
+    pub impulse_y: f64,
+}
+
+/// Identities that may call admin reducers. `init` adds the identity that published the module.
+#[spacetimedb::table(name = admins)]
+pub struct Admin {
+    #[primary_key]
+    pub identity: Identity,
+}
+
+fn is_admin(ctx: &ReducerContext) -> bool {
+    ctx.db.admins().identity().find(ctx.sender).is_some()
+}
+
+/// Returns an error if the sender is not an admin, `action` is used for logging.
+fn require_admin(ctx: &ReducerContext, action: &str) -> Result<(), String> {
+    if !is_admin(ctx) {
+        log::warn!(
+            "Unauthorized attempt to {} from identity {}",
+            action,
+            ctx.sender
+        );
+        return Err("Only admins may do this".to_string());
+    }
+    Ok(())
+}
+
+/// Identities that are not allowed to connect.
+#[spacetimedb::table(name = banned)]
+pub struct Ban {
+    #[primary_key]
+    pub identity: Identity,
+    pub banned_by: Identity,
+    pub banned_at: Timestamp,
+    // None bans forever
+    pub expires_at: Option<Timestamp>,
+}
+
+impl Ban {
+    // kicked players may only reconnect after this time
+    pub const KICK_MICROS: i64 = 60_000_000;
+
+    pub fn is_active(&self, now: Timestamp) -> bool {
+        self.expires_at.is_none_or(|expires_at| now < expires_at)
+    }
+
+    fn upsert(
+        ctx: &ReducerContext,
+        identity: Identity,
+        banned_by: Identity,
+        expires_at: Option<Timestamp>,
+    ) {
+        let ban = Ban {
+            identity,
+            banned_by,
+            banned_at: ctx.timestamp,
+            expires_at,
+        };
+        if ctx.db.banned().identity().find(identity).is_some() {
+            ctx.db.banned().identity().update(ban);
+        } else {
+            ctx.db.banned().insert(ban);
+        }
+    }
+}
+
+/// Token bucket and violation count of a player's input reducers.
+/// Rows are created on the first input and kept across reconnects, so reconnecting doesn't
+/// reset the violations.
+#[spacetimedb::table(name = input_limits)]
+pub struct InputLimit {
+    #[primary_key]
+    pub identity: Identity,
+    // refills by one token per physics tick, each input costs one token
+    pub tokens: f64,
+    pub refilled_at: Timestamp,
+    // malformed inputs. rate limited inputs are only dropped, fast clients hit the limit too
+    pub violations: u32,
+}
+
+impl InputLimit {
+    // how many inputs can be sent in a single tick
+    pub const BURST: f64 = 3.0;
+    pub const MAX_VIOLATIONS: u32 = 100;
+}
+
+/// Validates an input of the sender and takes a token from their bucket.
+/// Returns whether the input should be applied. Malformed inputs count as violations,
+/// and senders with too many violations get kicked.
+///
+/// Rejected inputs are dropped instead of returning an error, since an error would roll back
+/// the violation count.
+fn accept_input(ctx: &ReducerContext, config: &GameConfig, values: &[f64]) -> bool {
+    let mut limit = ctx
+        .db
+        .input_limits()
+        .identity()
+        .find(ctx.sender)
+        .unwrap_or(InputLimit {
+            identity: ctx.sender,
+            tokens: InputLimit::BURST,
+            refilled_at: ctx.timestamp,
+            violations: 0,
+        });
+
+    let elapsed_micros =
+        ctx.timestamp.to_micros_since_unix_epoch() - limit.refilled_at.to_micros_since_unix_epoch();
+    limit.tokens =
+        (limit.tokens + elapsed_micros as f64 / config.tick_micros as f64).min(InputLimit::BURST);
+    limit.refilled_at = ctx.timestamp;
+
+    // hypot doesn't overflow on the squares, so this only rejects NaN and infinite components
+    // and vectors too long for an f64, which the physics could not scale down
+    let magnitude = values.iter().fold(0.0, |acc: f64, v| acc.hypot(*v));
+    let malformed = !magnitude.is_finite();
+    let accepted = if malformed {
+        log::warn!("Rejected non-finite input from identity {}", ctx.sender);
+        limit.violations += 1;
+        false
+    } else if limit.tokens < 1.0 {
+        false
+    } else {
+        limit.tokens -= 1.0;
+        true
+    };
+    let kicked = malformed && limit.violations >= InputLimit::MAX_VIOLATIONS;
+
+    if ctx.db.input_limits().identity().find(ctx.sender).is_some() {
+        ctx.db.input_limits().identity().update(limit);
+    } else {
+        ctx.db.input_limits().insert(limit);
+    }
+    if kicked {
+        log::info!("Kicking {} for too many input violations", ctx.sender);
+        kick(ctx, ctx.sender, ctx.identity());
+        return false;
+    }
+    accepted
+}
+
+/// Game parameters that can be tuned on a live server.
+/// There is exactly one row with id `GameConfig::ID`, which `init` seeds with the defaults.
+#[derive(Clone)]
+#[spacetimedb::table(name = game_config, public)]
+pub struct GameConfig {
+    #[primary_key]
+    pub id: u32,
+    pub world_border_min_x: f64,
+    pub world_border_max_x: f64,
+    pub world_border_min_y: f64,
+    pub world_border_max_y: f64,
+    pub default_radius: f64,
+    pub respawn_micros: i64,
+    pub delta_radius_required_for_eating: f64,
+    // fraction of the velocity that is left after one second
+    pub drag_per_second: f64,
+    pub food_mass: f64,
+    pub max_foods: u64,
+    pub max_impulse: f64,
+    pub tick_micros: i64,
+    pub broadphase: BroadphaseKind,
+    // fraction of the approach speed that is left after a bounce off a ball or a wall
+    pub restitution: f64,
+}
+
+/// See `physics::BroadphaseKind`.
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BroadphaseKind {
+    SpatialHash,
+    SweepAndPrune,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        // the physics defaults live in the physics crate, so both sides agree on them
+        let physics = physics::Config::default();
+        GameConfig {
+            id: GameConfig::ID,
+            world_border_min_x: physics.world_border_min_x,
+            world_border_max_x: physics.world_border_max_x,
+            world_border_min_y: physics.world_border_min_y,
+            world_border_max_y: physics.world_border_max_y,
+            default_radius: physics.default_radius,
+            respawn_micros: 2_000_000,
+            delta_radius_required_for_eating: physics.delta_radius_required_for_eating,
+            drag_per_second: physics.drag_per_second,
+            food_mass: 3.0,
+            max_foods: 1000,
+            max_impulse: physics.max_impulse,
+            tick_micros: 16_666,
+            broadphase: match physics.broadphase {
+                physics::BroadphaseKind::SpatialHash => BroadphaseKind::SpatialHash,
+                physics::BroadphaseKind::SweepAndPrune => BroadphaseKind::SweepAndPrune,
+            },
+            restitution: physics.restitution,
+        }
+    }
+}
+
+impl GameConfig {
+    pub const ID: u32 = 0;
+
+    pub fn get(ctx: &ReducerContext) -> Self {
+        ctx.db.game_config().id().find(Self::ID).unwrap_or_default()
+    }
+
+    pub fn physics(&self) -> physics::Config {
+        physics::Config {
+            world_border_min_x: self.world_border_min_x,
+            world_border_max_x: self.world_border_max_x,
+            world_border_min_y: self.world_border_min_y,
+            world_border_max_y: self.world_border_max_y,
+            default_radius: self.default_radius,
+            delta_radius_required_for_eating: self.delta_radius_required_for_eating,
+            drag_per_second: self.drag_per_second,
+            restitution: self.restitution,
+            max_impulse: self.max_impulse,
+            broadphase: match self.broadphase {
+                BroadphaseKind::SpatialHash => physics::BroadphaseKind::SpatialHash,
+                BroadphaseKind::SweepAndPrune => physics::BroadphaseKind::SweepAndPrune,
+            },
+        }
+    }
+
+    pub fn random_pos_in_game_field(&self, rng: &mut impl physics::Rng) -> (f64, f64) {
+        self.physics().random_pos_in_game_field(rng)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        // NaN passes every comparison below, and would spread to all balls it touches
+        let floats = [
+            ("world_border_min_x", self.world_border_min_x),
+            ("world_border_max_x", self.world_border_max_x),
+            ("world_border_min_y", self.world_border_min_y),
+            ("world_border_max_y", self.world_border_max_y),
+            ("default_radius", self.default_radius),
+            (
+                "delta_radius_required_for_eating",
+                self.delta_radius_required_for_eating,
+            ),
+            ("drag_per_second", self.drag_per_second),
+            ("food_mass", self.food_mass),
+            ("max_impulse", self.max_impulse),
+            ("restitution", self.restitution),
+        ];
+        if let Some((name, _)) = floats.iter().find(|(_, value)| !value.is_finite()) {
+            return Err(format!("{} must be a finite number", name));
+        }
+        if self.world_border_min_x >= self.world_border_max_x
+            || self.world_border_min_y >= self.world_border_max_y
+        {
+            return Err("World border minimums must be less than their maximums".to_string());
+        }
+        // the world must be able to hold a virus
+        if self.world_border_max_x - self.world_border_min_x <= 2.0 * physics::Virus::RADIUS
+            || self.world_border_max_y - self.world_border_min_y <= 2.0 * physics::Virus::RADIUS
+        {
+            return Err("World borders must span more than a virus".to_string());
+        }
+        if !(self.default_radius > 0.0) {
+            return Err("default_radius must be positive".to_string());
+        }
+        if self.respawn_micros < 0 {
+            return Err("respawn_micros must not be negative".to_string());
+        }
+        if !(self.delta_radius_required_for_eating >= 0.0) {
+            return Err("delta_radius_required_for_eating must not be negative".to_string());
+        }
+        if !(0.0..=1.0).contains(&self.drag_per_second) {
+            return Err("drag_per_second must be between 0 and 1".to_string());
+        }
+        if !(self.food_mass > 0.0) {
+            return Err("food_mass must be positive".to_string());
+        }
+        if !(self.max_impulse >= 0.0) {
+            return Err("max_impulse must not be negative".to_string());
+        }
+        if self.tick_micros <= 0 {
+            return Err("tick_micros must be positive".to_string());
+        }
+        if !(0.0..=1.0).contains(&self.restitution) {
+            return Err("restitution must be between 0 and 1".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
 #[spacetimedb::table(name = foods, public)]
 pub struct Food {
     #[primary_key]
@@ -49,12 +372,83 @@ pub struct Food {
     pub id: u64,
     pub x: f64,
     pub y: f64,
+    // only ejected pellets move, regular foods stay at rest
+    pub vx: f64,
+    pub vy: f64,
+    pub mass: f64,
     pub color: Rgb,
+    // the player who ejected this pellet. they can't eat it until it comes to rest.
+    pub owner: Option<Identity>,
 }
 
 impl Food {
-    pub const MASS: f64 = 3.0;
-    pub const MAX_FOODS: u64 = 1000;
+    fn to_physics(&self) -> physics::Food<Identity> {
+        physics::Food {
+            id: self.id,
+            x: self.x,
+            y: self.y,
+            vx: self.vx,
+            vy: self.vy,
+            mass: self.mass,
+            owner: self.owner,
+        }
+    }
+
+    fn from_physics(food: physics::Food<Identity>, color: Rgb) -> Self {
+        Food {
+            id: food.id,
+            x: food.x,
+            y: food.y,
+            vx: food.vx,
+            vy: food.vy,
+            mass: food.mass,
+            color,
+            owner: food.owner,
+        }
+    }
+}
+
+#[derive(Clone)]
+#[spacetimedb::table(name = viruses, public)]
+pub struct Virus {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub x: f64,
+    pub y: f64,
+    pub radius: f64,
+    // number of ejected pellets this virus has been fed since it last spawned a new virus
+    pub fed: u32,
+}
+
+impl Virus {
+    // spawn_food keeps the world topped up with this many viruses
+    pub const MIN_VIRUSES: u64 = 10;
+
+    pub fn spawn_virus(rng: &mut impl physics::Rng, config: &GameConfig) -> Self {
+        let (x, y) = config.random_pos_in_game_field(rng);
+        Virus::from_physics(physics::Virus::new_at(&config.physics(), x, y))
+    }
+
+    fn to_physics(&self) -> physics::Virus {
+        physics::Virus {
+            id: self.id,
+            x: self.x,
+            y: self.y,
+            radius: self.radius,
+            fed: self.fed,
+        }
+    }
+
+    fn from_physics(virus: physics::Virus) -> Self {
+        Virus {
+            id: virus.id,
+            x: virus.x,
+            y: virus.y,
+            radius: virus.radius,
+            fed: virus.fed,
+        }
+    }
 }
 
 #[derive(SpacetimeType, Clone, Copy)]
@@ -64,11 +458,78 @@ pub struct Rgb {
     pub b: u8,
 }
 
+impl Rgb {
+    pub fn random(rng: &mut impl physics::Rng) -> Self {
+        let mut channel = || (rng.next_f64() * 256.0) as u8;
+        Rgb {
+            r: channel(),
+            g: channel(),
+            b: channel(),
+        }
+    }
+}
+
+#[derive(Clone)]
+#[spacetimedb::table(name = players, public)]
+pub struct Player {
+    #[primary_key]
+    pub identity: Identity,
+    // unique among players, offline players get their default name back
+    pub name: String,
+    // all cells of this player get this color
+    pub color: Rgb,
+    pub joined_at: Timestamp,
+    pub online: bool,
+    // sequence number of the last input the physics tick applied, so clients can reconcile
+    pub last_input_seq: u64,
+}
+
+impl Player {
+    pub const MAX_NAME_LENGTH: usize = 16;
+
+    fn default_name(identity: Identity) -> String {
+        // the leading bytes of identities are shared, so use the trailing ones
+        let hex = identity.to_string();
+        format!("player-{}", &hex[hex.len() - 6..])
+    }
+
+    fn validate_name(ctx: &ReducerContext, name: &str) -> Result<(), String> {
+        if name.is_empty() {
+            return Err("Name must not be empty".to_string());
+        }
+        if name.chars().count() > Self::MAX_NAME_LENGTH {
+            return Err(format!(
+                "Name must be at most {} characters long",
+                Self::MAX_NAME_LENGTH
+            ));
+        }
+        if !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == ' ' || c == '_' || c == '-')
+        {
+            return Err("Name may only contain letters, digits, spaces, '_' and '-'".to_string());
+        }
+        let taken = ctx
+            .db
+            .players()
+            .iter()
+            .any(|p| p.identity != ctx.sender && p.name.eq_ignore_ascii_case(name));
+        if taken {
+            return Err(format!("Name '{}' is already taken", name));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Clone)]
 #[spacetimedb::table(name = balls, public)]
 pub struct Ball {
     #[primary_key]
-    pub identity: Identity,
+    #[auto_inc]
+    pub id: u64,
+    // the player this cell belongs to. a player can own multiple cells after splitting.
+    #[index(btree)]
+    pub owner: Identity,
     pub x: f64,
     pub y: f64,
     pub vx: f64,
@@ -76,145 +537,70 @@ pub struct Ball {
     pub radius: f64,
     pub color: Rgb,
     pub dead: bool,
+    // cells of the same owner bounce off each other until this time, after which they merge on contact
+    pub merge_at: Timestamp,
 }
 
 impl Ball {
-    pub const DEFAULT_RADIUS: f64 = 4.0;
-    pub const WORLD_BORDER_MIN_X: f64 = -200.0;
-    pub const WORLD_BORDER_MAX_X: f64 = 200.0;
-    pub const WORLD_BORDER_MIN_Y: f64 = -200.0;
-    pub const WORLD_BORDER_MAX_Y: f64 = 200.0;
-    pub const RESPAWN_MICROS: i64 = 2_000_000;
-    pub const DELTA_RADIUS_REQUIRED_FOR_EATING: f64 = 3.0;
-
     pub fn mass(&self) -> f64 {
         self.radius * self.radius * std::f64::consts::PI
     }
 
-    pub fn random_pos_in_game_field(ctx: &ReducerContext) -> (f64, f64) {
-        let x = ctx.random::<f64>() * (Ball::WORLD_BORDER_MAX_X - Ball::WORLD_BORDER_MIN_X)
-            + Ball::WORLD_BORDER_MIN_X;
-        let y = ctx.random::<f64>() * (Ball::WORLD_BORDER_MAX_Y - Ball::WORLD_BORDER_MIN_Y)
-            + Ball::WORLD_BORDER_MIN_Y;
-        (x, y)
-    }
-
-    pub fn spawn_ball(ctx: &ReducerContext, for_identity: Identity) -> Self {
-        let rgb = Rgb {
-            r: ctx.random(),
-            g: ctx.random(),
-            b: ctx.random(),
-        };
-        let (x, y) = Ball::random_pos_in_game_field(ctx);
+    pub fn spawn_ball(
+        store: &mut impl GameStore,
+        config: &GameConfig,
+        for_identity: Identity,
+    ) -> Self {
+        let rgb = store
+            .player(for_identity)
+            .map(|p| p.color)
+            .unwrap_or_else(|| Rgb::random(store));
+        let (x, y) = config.random_pos_in_game_field(store);
         let ball = Ball {
-            identity: for_identity,
+            id: 0,
+            owner: for_identity,
             x,
             y,
             vx: 0.0,
             vy: 0.0,
-            radius: Ball::DEFAULT_RADIUS,
+            radius: config.default_radius,
             color: rgb,
             dead: false,
+            merge_at: store.now(),
         };
         ball
     }
 
-    fn update_mass(&mut self, new_mass: f64) {
-        self.radius = (new_mass / std::f64::consts::PI).sqrt();
-    }
-
-    fn add_mass(&mut self, mass: f64) {
-        self.update_mass(self.mass() + mass);
-    }
-
-    fn respawn(&mut self, ctx: &ReducerContext) {
-        let when = ctx.timestamp + TimeDuration::from_micros(Self::RESPAWN_MICROS);
-        let schedule = RespawnBallsSchedule {
-            scheduled_id: 0,
-            scheduled_at: when.into(),
-            respawn_for_identity: self.identity,
-        };
-        let res = ctx.db.respawn_balls_schedule().try_insert(schedule);
-        if let Err(err) = res {
-            log::error!("Failed to schedule respawn: {}", err);
-        }
-    }
-
-    fn handle_eating<'a>(ctx: &ReducerContext, mut ball1: &'a mut Ball, mut ball2: &'a mut Ball) {
-        let mut mass1 = ball1.mass();
-        let mut mass2 = ball2.mass();
-        if mass2 > mass1 {
-            (ball1, ball2) = (ball2, ball1);
-            (mass1, mass2) = (mass2, mass1);
-        }
-
-        // ball1 eats ball2
-        let new_mass1 = mass1 + mass2;
-        ball1.update_mass(new_mass1);
-        ball2.respawn(ctx);
-        ball2.dead = true;
-    }
-
-    // returns whether there has been an update or not
-    pub fn handle_collision(&mut self, other: &mut Ball, ctx: &ReducerContext) -> bool {
-        let mut did_update = false;
-
-        let dx = self.x - other.x;
-        let dy = self.y - other.y;
-        let distance = (dx * dx + dy * dy).sqrt();
-        let overlap = self.radius + other.radius - distance;
-        if overlap > 0.0 {
-            // we are colliding
-
-            // determine if one eats the other or if they bounce off each other
-            if (self.radius - other.radius).abs() > Self::DELTA_RADIUS_REQUIRED_FOR_EATING {
-                // eating will happen
-                Ball::handle_eating(ctx, self, other);
-            } else {
-                // update positions
-                let overlap = overlap / 2.0;
-                let dx = dx / distance * overlap;
-                let dy = dy / distance * overlap;
-                self.x += dx;
-                self.y += dy;
-                other.x -= dx;
-                other.y -= dy;
-
-                // also update velocities, but take into account the mass of each ball
-                let normal_x = dx / overlap;
-                let normal_y = dy / overlap;
-                let relative_velocity_x = self.vx - other.vx;
-                let relative_velocity_y = self.vy - other.vy;
-                let dot_product = relative_velocity_x * normal_x + relative_velocity_y * normal_y;
-                let self_mass = self.mass();
-                let other_mass = other.mass();
-                let impulse = 2.0 * dot_product / (self_mass + other_mass);
-                self.vx -= impulse * normal_x * other_mass;
-                self.vy -= impulse * normal_y * other_mass;
-                other.vx += impulse * normal_x * self_mass;
-                other.vy += impulse * normal_y * self_mass;
-            }
-
-            did_update = true;
+    fn to_physics(&self) -> physics::Ball<Identity> {
+        physics::Ball {
+            id: self.id,
+            owner: self.owner,
+            x: self.x,
+            y: self.y,
+            vx: self.vx,
+            vy: self.vy,
+            radius: self.radius,
+            dead: self.dead,
+            merge_at_micros: self.merge_at.to_micros_since_unix_epoch(),
         }
-
-        did_update
     }
-}
 
-impl SpatialHashable for Ball {
-    fn get_aabb(&self) -> Aabb {
-        Aabb {
-            min_x: (self.x - self.radius).floor() as i64,
-            min_y: (self.y - self.radius).floor() as i64,
-            max_x: (self.x + self.radius).floor() as i64,
-            max_y: (self.y + self.radius).floor() as i64,
+    fn from_physics(ball: physics::Ball<Identity>, color: Rgb) -> Self {
+        Ball {
+            id: ball.id,
+            owner: ball.owner,
+            x: ball.x,
+            y: ball.y,
+            vx: ball.vx,
+            vy: ball.vy,
+            radius: ball.radius,
+            color,
+            dead: ball.dead,
+            merge_at: Timestamp::from_micros_since_unix_epoch(ball.merge_at_micros),
         }
     }
 }
 
-const DRAG: f64 = 0.95;
-
 #[spacetimedb::reducer]
 fn respawn_ball(ctx: &ReducerContext, schedule: RespawnBallsSchedule) {
     if ctx.sender != ctx.identity() {
@@ -224,23 +610,10 @@ fn respawn_ball(ctx: &ReducerContext, schedule: RespawnBallsSchedule) {
         );
         return;
     }
-    if ctx
-        .db
-        .balls()
-        .identity()
-        .find(schedule.respawn_for_identity)
-        .is_none()
-    {
-        // player disconnected, no need to respawn
-        return;
-    }
-
-    // insert a new ball for identity
-    let ball = Ball::spawn_ball(ctx, schedule.respawn_for_identity);
-    ctx.db.balls().identity().update(ball);
+    game::respawn_ball(&mut CtxStore(ctx), schedule.respawn_for_identity);
 }
 
-/// Runs every physics tick and updates each ball's position
+/// Runs every physics tick, see [`game::tick`].
 #[spacetimedb::reducer]
 fn update_balls(ctx: &ReducerContext, _schedule: UpdateBallsSchedule) {
     if ctx.sender != ctx.identity() {
@@ -250,182 +623,272 @@ fn update_balls(ctx: &ReducerContext, _schedule: UpdateBallsSchedule) {
         );
         return;
     }
+    game::tick(&mut CtxStore(ctx));
+}
 
-    // insert physics tick
-    let tick = PhysicsTick {
-        tick_id: 0,
-        ticked_at: ctx.timestamp,
-    };
-    ctx.db.physics_ticks().insert(tick);
-    // delete ticks older than 1 second
-    let one_second_ago = ctx.timestamp + TimeDuration::from_micros(-1_000_000);
-    for tick in ctx
-        .db
-        .physics_ticks()
-        .iter()
-        .filter(|t| t.ticked_at < one_second_ago)
-    {
-        ctx.db.physics_ticks().tick_id().delete(tick.tick_id);
+/// Ranks all players by the total mass of their cells
+#[spacetimedb::reducer]
+fn update_leaderboard(ctx: &ReducerContext, _schedule: UpdateLeaderboardSchedule) {
+    if ctx.sender != ctx.identity() {
+        log::warn!(
+            "Unauthorized attempt to update leaderboard from identity {}",
+            ctx.sender
+        );
+        return;
     }
 
-    // skip
-    // return;
+    // dead players still get ranked, with a score of 0
+    let mut scores = HashMap::<Identity, f64>::new();
+    for ball in ctx.db.balls().iter() {
+        let score = scores.entry(ball.owner).or_default();
+        if !ball.dead {
+            *score += ball.mass();
+        }
+    }
+    let mut scores = scores.into_iter().collect::<Vec<_>>();
+    // highest score first, ties are broken by identity to keep ranks stable
+    scores.sort_by(|(owner_a, score_a), (owner_b, score_b)| {
+        score_b.total_cmp(score_a).then(owner_a.cmp(owner_b))
+    });
+
+    let mut ranked = HashSet::new();
+    for (idx, (owner, score)) in scores.into_iter().enumerate() {
+        let entry = LeaderboardEntry {
+            owner,
+            rank: idx as u32 + 1,
+            score: score.round() as u64,
+        };
+        ranked.insert(owner);
+        match ctx.db.leaderboard().owner().find(owner) {
+            Some(old) if old.rank == entry.rank && old.score == entry.score => {}
+            Some(_) => {
+                ctx.db.leaderboard().owner().update(entry);
+            }
+            None => {
+                ctx.db.leaderboard().insert(entry);
+            }
+        }
+    }
 
-    let mut balls = ctx
-        .db
-        .balls()
-        .iter()
-        .filter(|b| !b.dead)
-        .collect::<Vec<_>>();
+    // remove players that disconnected
+    for entry in ctx.db.leaderboard().iter() {
+        if !ranked.contains(&entry.owner) {
+            ctx.db.leaderboard().owner().delete(entry.owner);
+        }
+    }
+}
 
-    // (food, keep) pairs
-    let mut foods = ctx.db.foods().iter().map(|f| (f, true)).collect::<Vec<_>>();
+/// Queues an impulse for the sender's cells, see [`game::queue_input`].
+#[spacetimedb::reducer]
+fn apply_impulse(ctx: &ReducerContext, seq: u64, impulse_x: f64, impulse_y: f64) {
+    let config = GameConfig::get(ctx);
+    if !accept_input(ctx, &config, &[impulse_x, impulse_y]) {
+        return;
+    }
 
-    // Update positions individually
-    for ball in &mut balls {
-        ball.vx *= DRAG;
-        ball.vy *= DRAG;
+    game::queue_input(&mut CtxStore(ctx), ctx.sender, seq, impulse_x, impulse_y);
+}
 
-        ball.x += ball.vx;
-        ball.y += ball.vy;
+/// Splits the sender's cells, see [`game::split`].
+#[spacetimedb::reducer]
+fn split(ctx: &ReducerContext, aim_x: f64, aim_y: f64) {
+    let config = GameConfig::get(ctx);
+    if !accept_input(ctx, &config, &[aim_x, aim_y]) {
+        return;
     }
 
-    // Update wall collisions with WORLD_BORDER
-    for ball in &mut balls {
-        if ball.x - ball.radius < Ball::WORLD_BORDER_MIN_X {
-            ball.x = Ball::WORLD_BORDER_MIN_X + ball.radius;
-            ball.vx = -ball.vx;
-        }
-        if ball.x + ball.radius > Ball::WORLD_BORDER_MAX_X {
-            ball.x = Ball::WORLD_BORDER_MAX_X - ball.radius;
-            ball.vx = -ball.vx;
-        }
-        if ball.y - ball.radius < Ball::WORLD_BORDER_MIN_Y {
-            ball.y = Ball::WORLD_BORDER_MIN_Y + ball.radius;
-            ball.vy = -ball.vy;
-        }
-        if ball.y + ball.radius > Ball::WORLD_BORDER_MAX_Y {
-            ball.y = Ball::WORLD_BORDER_MAX_Y - ball.radius;
-            ball.vy = -ball.vy;
-        }
+    game::split(&mut CtxStore(ctx), ctx.sender, aim_x, aim_y);
+}
+
+/// Ejects mass from the sender's cells, see [`game::eject_mass`].
+#[spacetimedb::reducer]
+fn eject_mass(ctx: &ReducerContext, aim_x: f64, aim_y: f64) {
+    let config = GameConfig::get(ctx);
+    if !accept_input(ctx, &config, &[aim_x, aim_y]) {
+        return;
     }
 
-    // Handle food eating
-    for ball in &mut balls {
-        for (food, keep) in &mut foods {
-            if !*keep {
-                continue;
-            }
-            let dx = ball.x - food.x;
-            let dy = ball.y - food.y;
-            let distance = (dx * dx + dy * dy).sqrt();
-            if distance < ball.radius {
-                // ball eats food
-                ball.add_mass(Food::MASS);
-                *keep = false;
-            }
-        }
+    game::eject_mass(&mut CtxStore(ctx), ctx.sender, aim_x, aim_y);
+}
+
+/// Sets the sender's nickname
+#[spacetimedb::reducer]
+fn set_name(ctx: &ReducerContext, name: String) -> Result<(), String> {
+    let name = name.trim();
+    Player::validate_name(ctx, name)?;
+
+    let Some(player) = ctx.db.players().identity().find(ctx.sender) else {
+        return Err("Player not found".to_string());
+    };
+    ctx.db.players().identity().update(Player {
+        name: name.to_string(),
+        ..player
+    });
+    Ok(())
+}
+
+/// Sets the color of the sender and all of their cells
+#[spacetimedb::reducer]
+fn set_color(ctx: &ReducerContext, color: Rgb) -> Result<(), String> {
+    let Some(player) = ctx.db.players().identity().find(ctx.sender) else {
+        return Err("Player not found".to_string());
+    };
+    ctx.db
+        .players()
+        .identity()
+        .update(Player { color, ..player });
+
+    for ball in ctx.db.balls().owner().filter(&ctx.sender) {
+        ctx.db.balls().id().update(Ball { color, ..ball });
     }
+    Ok(())
+}
 
-    // Update collisions
-    // Note: at 4000 balls, this is around ~45-50ms, while below shg implementation is ~30-35ms
-    // TODO: also use shg to update foods.
-    // TODO: store shg across ticks
-    // for ball1_idx in 0..balls.len() {
-    //     if balls[ball1_idx].dead {
-    //         continue;
-    //     }
-    //     for ball2_idx in ball1_idx+1..balls.len() {
-    //         if balls[ball2_idx].dead {
-    //             continue;
-    //         }
-    //         let (balls1, balls2) = balls.split_at_mut(ball2_idx);
-    //         let ball1 = &mut balls1[ball1_idx];
-    //         let ball2 = &mut balls2[0];
-    //
-    //         ball1.handle_collision(ball2, ctx);
-    //         if ball1.dead {
-    //             break;
-    //         }
-    //     }
-    //
-    // }
-
-    // update collisions fast
-    let mut grid = SpatialHashGrid::new(10);
-    for (idx, ball) in balls.iter().enumerate() {
-        grid.insert_with_aabb(idx, ball.get_aabb());
-    }
-
-    for idx1 in 0..balls.len() {
-        let aabb = balls[idx1].get_aabb();
-        for &idx2 in grid.get_for_aabb(aabb) {
-            if idx1 == idx2 {
-                continue;
-            }
-            if balls[idx1].dead {
-                break;
-            }
-            if balls[idx2].dead {
-                continue;
-            }
-            let idx_min = idx1.min(idx2);
-            let idx_max = idx1.max(idx2);
-            let (balls1, balls2) = balls.split_at_mut(idx_max);
-            let ball1 = &mut balls1[idx_min];
-            let ball2 = &mut balls2[0];
-            ball1.handle_collision(ball2, ctx);
-            if ball1.dead {
-                break;
-            }
+/// Replaces the game config. Only admins may do this.
+#[spacetimedb::reducer]
+fn set_config(ctx: &ReducerContext, config: GameConfig) -> Result<(), String> {
+    require_admin(ctx, "set config")?;
+    config.validate()?;
+
+    let config = GameConfig {
+        id: GameConfig::ID,
+        ..config
+    };
+    let old_config = GameConfig::get(ctx);
+    if config.tick_micros != old_config.tick_micros {
+        // reschedule the physics loop with the new interval
+        for schedule in ctx.db.update_balls_schedule().iter() {
+            ctx.db
+                .update_balls_schedule()
+                .scheduled_id()
+                .delete(schedule.scheduled_id);
         }
+        ctx.db.update_balls_schedule().insert(UpdateBallsSchedule {
+            scheduled_id: 0,
+            scheduled_at: TimeDuration::from_micros(config.tick_micros).into(),
+        });
     }
 
-    for ball in balls {
-        ctx.db.balls().identity().update(ball.clone());
+    if ctx.db.game_config().id().find(GameConfig::ID).is_some() {
+        ctx.db.game_config().id().update(config);
+    } else {
+        ctx.db.game_config().insert(config);
     }
-    for (food, keep) in foods {
-        if !keep {
-            ctx.db.foods().id().delete(food.id);
-        }
+    Ok(())
+}
+
+/// Removes the cells and the leaderboard entry of a player, see [`game::remove_cells`].
+fn remove_from_world(ctx: &ReducerContext, identity: Identity) {
+    game::remove_cells(&mut CtxStore(ctx), identity);
+    ctx.db.leaderboard().owner().delete(identity);
+}
+
+/// Removes a player from the world. Modules can't close connections,
+/// so the player is banned for `Ban::KICK_MICROS` and has to reconnect afterwards.
+fn kick(ctx: &ReducerContext, identity: Identity, kicked_by: Identity) {
+    remove_from_world(ctx, identity);
+    let expires_at = ctx.timestamp + TimeDuration::from_micros(Ban::KICK_MICROS);
+    Ban::upsert(ctx, identity, kicked_by, Some(expires_at));
+}
+
+/// Kicks a player out of the world, see `kick`.
+#[spacetimedb::reducer]
+fn kick_player(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    require_admin(ctx, "kick player")?;
+    if ctx.db.admins().identity().find(identity).is_some() {
+        return Err("Admins can't be kicked".to_string());
     }
+
+    log::info!("{} kicked {}", ctx.sender, identity);
+    kick(ctx, identity, ctx.sender);
+    Ok(())
 }
 
-/// Applies an impulse to the sender's ball
+/// Bans an identity for `duration_micros`, or forever if `None`, and removes it from the world.
 #[spacetimedb::reducer]
-fn apply_impulse(ctx: &ReducerContext, mut impulse_x: f64, mut impulse_y: f64) {
-    let mut ball = ctx.db.balls().identity().find(ctx.sender).unwrap();
+fn ban_identity(
+    ctx: &ReducerContext,
+    identity: Identity,
+    duration_micros: Option<i64>,
+) -> Result<(), String> {
+    require_admin(ctx, "ban identity")?;
+    if ctx.db.admins().identity().find(identity).is_some() {
+        return Err("Admins can't be banned".to_string());
+    }
+    if duration_micros.is_some_and(|micros| micros <= 0) {
+        return Err("Ban duration must be positive".to_string());
+    }
+
+    log::info!("{} banned {}", ctx.sender, identity);
+    remove_from_world(ctx, identity);
+    let expires_at =
+        duration_micros.map(|micros| ctx.timestamp + TimeDuration::from_micros(micros));
+    Ban::upsert(ctx, identity, ctx.sender, expires_at);
+    Ok(())
+}
 
-    // cap impulse
-    let impulse = (impulse_x * impulse_x + impulse_y * impulse_y).sqrt();
-    let max_impulse = 20.0;
-    if impulse > max_impulse {
-        let scale = max_impulse / impulse;
-        impulse_x *= scale;
-        impulse_y *= scale;
+/// Lifts the ban of an identity
+#[spacetimedb::reducer]
+fn unban_identity(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    require_admin(ctx, "unban identity")?;
+    if !ctx.db.banned().identity().delete(identity) {
+        return Err("Identity is not banned".to_string());
     }
+    Ok(())
+}
 
-    // take into account ball's mass
-    // heavier balls get a less significant velocity change from the same impulse.
-    let mass = ball.mass();
-    // let mass_normalizer = Ball::DEFAULT_RADIUS * Ball::DEFAULT_RADIUS * std::f64::consts::PI;
-    let mass_normalizer = Ball::DEFAULT_RADIUS;
-    impulse_x /= (ball.radius - Ball::DEFAULT_RADIUS + 1.0).sqrt();
-    impulse_y /= (ball.radius - Ball::DEFAULT_RADIUS + 1.0).sqrt();
+/// Removes all foods, including ejected pellets. `spawn_food` refills the world afterwards.
+#[spacetimedb::reducer]
+fn clear_foods(ctx: &ReducerContext) -> Result<(), String> {
+    require_admin(ctx, "clear foods")?;
+    for food in ctx.db.foods().iter() {
+        ctx.db.foods().id().delete(food.id);
+    }
+    Ok(())
+}
 
-    ball.vx += impulse_x;
-    ball.vy += impulse_y;
+/// Clears foods and viruses and respawns every online player with a single fresh cell.
+#[spacetimedb::reducer]
+fn reset_world(ctx: &ReducerContext) -> Result<(), String> {
+    require_admin(ctx, "reset world")?;
+    log::info!("{} reset the world", ctx.sender);
 
-    // cap max velocity, different max per radius
-    let max_velocity = 10.0 / (ball.radius - Ball::DEFAULT_RADIUS + 1.0).powf(0.8);
-    let velocity = (ball.vx * ball.vx + ball.vy * ball.vy).sqrt();
-    if velocity > max_velocity {
-        let scale = max_velocity / velocity;
-        ball.vx *= scale;
-        ball.vy *= scale;
+    for food in ctx.db.foods().iter() {
+        ctx.db.foods().id().delete(food.id);
+    }
+    for virus in ctx.db.viruses().iter() {
+        ctx.db.viruses().id().delete(virus.id);
+    }
+    for ball in ctx.db.balls().iter() {
+        ctx.db.balls().id().delete(ball.id);
+    }
+    for schedule in ctx.db.respawn_balls_schedule().iter() {
+        ctx.db
+            .respawn_balls_schedule()
+            .scheduled_id()
+            .delete(schedule.scheduled_id);
+    }
+    for entry in ctx.db.leaderboard().iter() {
+        ctx.db.leaderboard().owner().delete(entry.owner);
     }
 
-    ctx.db.balls().identity().update(ball);
+    let config = GameConfig::get(ctx);
+    let mut store = CtxStore(ctx);
+    for player in ctx.db.players().iter().filter(|p| p.online) {
+        // kicked and banned players may still be connected
+        let banned = ctx
+            .db
+            .banned()
+            .identity()
+            .find(player.identity)
+            .is_some_and(|ban| ban.is_active(ctx.timestamp));
+        if banned {
+            continue;
+        }
+        let ball = Ball::spawn_ball(&mut store, &config, player.identity);
+        store.insert_ball(ball);
+    }
+    Ok(())
 }
 
 #[spacetimedb::reducer]
@@ -438,34 +901,23 @@ fn spawn_food(ctx: &ReducerContext, _schedule: SpawnFoodSchedule) {
         return;
     }
 
-    // Spawn a new food, but only if foods are not saturated yet
-    if ctx.db.foods().count() >= Food::MAX_FOODS {
-        return;
-    }
-
-    for _ in 0..100 {
-        let food = Food {
-            id: 0,
-            x: ctx.random::<f64>() * (Ball::WORLD_BORDER_MAX_X - Ball::WORLD_BORDER_MIN_X)
-                + Ball::WORLD_BORDER_MIN_X,
-            y: ctx.random::<f64>() * (Ball::WORLD_BORDER_MAX_Y - Ball::WORLD_BORDER_MIN_Y)
-                + Ball::WORLD_BORDER_MIN_Y,
-            color: Rgb {
-                r: ctx.random(),
-                g: ctx.random(),
-                b: ctx.random(),
-            },
-        };
-        ctx.db.foods().insert(food);
-    }
+    game::spawn_food(&mut CtxStore(ctx));
 }
 
 #[spacetimedb::reducer(init)]
 pub fn init(ctx: &ReducerContext) {
+    // The publisher of the module is the first admin
+    ctx.db.admins().insert(Admin {
+        identity: ctx.sender,
+    });
+
+    let config = GameConfig::default();
+    ctx.db.game_config().insert(config.clone());
+
     // Add scheduler for update_balls
     let schedule = UpdateBallsSchedule {
         scheduled_id: 0,
-        scheduled_at: TimeDuration::from_micros(16_666).into(),
+        scheduled_at: TimeDuration::from_micros(config.tick_micros).into(),
         // scheduled_at: TimeDuration::from_micros(200_000).into(),
     };
     ctx.db.update_balls_schedule().insert(schedule);
@@ -476,17 +928,31 @@ pub fn init(ctx: &ReducerContext) {
         scheduled_at: TimeDuration::from_micros(200_000).into(),
     };
     ctx.db.spawn_foods_schedule().insert(schedule);
+
+    // Add scheduler for update_leaderboard
+    let schedule = UpdateLeaderboardSchedule {
+        scheduled_id: 0,
+        scheduled_at: TimeDuration::from_micros(500_000).into(),
+    };
+    ctx.db.update_leaderboard_schedule().insert(schedule);
 }
 
 #[spacetimedb::reducer(client_connected)]
-pub fn identity_connected(ctx: &ReducerContext) {
-    // Add a new ball for the client
-    let ball = Ball::spawn_ball(ctx, ctx.sender);
-    ctx.db.balls().insert(ball);
+pub fn identity_connected(ctx: &ReducerContext) -> Result<(), String> {
+    if let Some(ban) = ctx.db.banned().identity().find(ctx.sender) {
+        if ban.is_active(ctx.timestamp) {
+            log::info!("Rejected banned identity {}", ctx.sender);
+            return Err("You are banned from this server".to_string());
+        }
+        ctx.db.banned().identity().delete(ctx.sender);
+    }
+
+    game::connect(&mut CtxStore(ctx), ctx.sender);
+    Ok(())
 }
 
 #[spacetimedb::reducer(client_disconnected)]
 pub fn identity_disconnected(ctx: &ReducerContext) {
-    // Remove the ball for the client
-    ctx.db.balls().identity().delete(ctx.sender);
+    game::disconnect(&mut CtxStore(ctx), ctx.sender);
+    ctx.db.leaderboard().owner().delete(ctx.sender);
 }
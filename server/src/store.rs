@@ -0,0 +1,389 @@
+use crate::{
+    Ball, Food, GameConfig, PendingInput, PhysicsTick, Player, RespawnBallsSchedule, Virus,
+};
+// the accessors of the tables on `ctx.db`
+use crate::{
+    balls, foods, pending_inputs, physics_ticks, players, respawn_balls_schedule, viruses,
+};
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+use std::collections::BTreeMap;
+use tagars_physics as physics;
+
+/// The tables, clock and RNG the game logic in `game` works with.
+///
+/// `CtxStore` is backed by the module's database, `MemoryStore` keeps everything in memory so the
+/// game logic can run in native tests. Ids of inserted rows are assigned by the store,
+/// like an `auto_inc` column.
+pub trait GameStore: physics::Rng {
+    /// The time of the current transaction.
+    fn now(&self) -> Timestamp;
+    fn config(&self) -> GameConfig;
+
+    fn balls(&self) -> Vec<Ball>;
+    fn balls_of(&self, owner: Identity) -> Vec<Ball>;
+    fn insert_ball(&mut self, ball: Ball);
+    fn update_ball(&mut self, ball: Ball);
+    fn delete_ball(&mut self, id: u64);
+
+    fn foods(&self) -> Vec<Food>;
+    fn food_count(&self) -> u64;
+    fn insert_food(&mut self, food: Food);
+    fn update_food(&mut self, food: Food);
+    fn delete_food(&mut self, id: u64);
+
+    fn viruses(&self) -> Vec<Virus>;
+    fn virus_count(&self) -> u64;
+    fn insert_virus(&mut self, virus: Virus);
+    fn update_virus(&mut self, virus: Virus);
+    fn delete_virus(&mut self, id: u64);
+
+    fn player(&self, identity: Identity) -> Option<Player>;
+    fn insert_player(&mut self, player: Player);
+    fn update_player(&mut self, player: Player);
+
+    fn pending_inputs(&self) -> Vec<PendingInput>;
+    fn pending_input(&self, owner: Identity) -> Option<PendingInput>;
+    fn insert_pending_input(&mut self, input: PendingInput);
+    fn update_pending_input(&mut self, input: PendingInput);
+    fn delete_pending_input(&mut self, owner: Identity);
+
+    /// The time of the most recent physics tick.
+    fn latest_tick(&self) -> Option<Timestamp>;
+    fn insert_tick(&mut self, ticked_at: Timestamp);
+    fn delete_ticks_before(&mut self, before: Timestamp);
+
+    /// Respawns the dead cell of `owner` at `at`, see `game::respawn_ball`.
+    fn schedule_respawn(&mut self, owner: Identity, at: Timestamp);
+}
+
+/// A `GameStore` on the tables of the module.
+pub struct CtxStore<'a>(pub &'a ReducerContext);
+
+impl physics::Rng for CtxStore<'_> {
+    fn next_f64(&mut self) -> f64 {
+        self.0.random()
+    }
+}
+
+impl GameStore for CtxStore<'_> {
+    fn now(&self) -> Timestamp {
+        self.0.timestamp
+    }
+
+    fn config(&self) -> GameConfig {
+        GameConfig::get(self.0)
+    }
+
+    fn balls(&self) -> Vec<Ball> {
+        self.0.db.balls().iter().collect()
+    }
+
+    fn balls_of(&self, owner: Identity) -> Vec<Ball> {
+        self.0.db.balls().owner().filter(&owner).collect()
+    }
+
+    fn insert_ball(&mut self, ball: Ball) {
+        self.0.db.balls().insert(ball);
+    }
+
+    fn update_ball(&mut self, ball: Ball) {
+        self.0.db.balls().id().update(ball);
+    }
+
+    fn delete_ball(&mut self, id: u64) {
+        self.0.db.balls().id().delete(id);
+    }
+
+    fn foods(&self) -> Vec<Food> {
+        self.0.db.foods().iter().collect()
+    }
+
+    fn food_count(&self) -> u64 {
+        self.0.db.foods().count()
+    }
+
+    fn insert_food(&mut self, food: Food) {
+        self.0.db.foods().insert(food);
+    }
+
+    fn update_food(&mut self, food: Food) {
+        self.0.db.foods().id().update(food);
+    }
+
+    fn delete_food(&mut self, id: u64) {
+        self.0.db.foods().id().delete(id);
+    }
+
+    fn viruses(&self) -> Vec<Virus> {
+        self.0.db.viruses().iter().collect()
+    }
+
+    fn virus_count(&self) -> u64 {
+        self.0.db.viruses().count()
+    }
+
+    fn insert_virus(&mut self, virus: Virus) {
+        self.0.db.viruses().insert(virus);
+    }
+
+    fn update_virus(&mut self, virus: Virus) {
+        self.0.db.viruses().id().update(virus);
+    }
+
+    fn delete_virus(&mut self, id: u64) {
+        self.0.db.viruses().id().delete(id);
+    }
+
+    fn player(&self, identity: Identity) -> Option<Player> {
+        self.0.db.players().identity().find(identity)
+    }
+
+    fn insert_player(&mut self, player: Player) {
+        self.0.db.players().insert(player);
+    }
+
+    fn update_player(&mut self, player: Player) {
+        self.0.db.players().identity().update(player);
+    }
+
+    fn pending_inputs(&self) -> Vec<PendingInput> {
+        self.0.db.pending_inputs().iter().collect()
+    }
+
+    fn pending_input(&self, owner: Identity) -> Option<PendingInput> {
+        self.0.db.pending_inputs().owner().find(owner)
+    }
+
+    fn insert_pending_input(&mut self, input: PendingInput) {
+        self.0.db.pending_inputs().insert(input);
+    }
+
+    fn update_pending_input(&mut self, input: PendingInput) {
+        self.0.db.pending_inputs().owner().update(input);
+    }
+
+    fn delete_pending_input(&mut self, owner: Identity) {
+        self.0.db.pending_inputs().owner().delete(owner);
+    }
+
+    fn latest_tick(&self) -> Option<Timestamp> {
+        self.0.db.physics_ticks().iter().map(|t| t.ticked_at).max()
+    }
+
+    fn insert_tick(&mut self, ticked_at: Timestamp) {
+        self.0.db.physics_ticks().insert(PhysicsTick {
+            tick_id: 0,
+            ticked_at,
+        });
+    }
+
+    fn delete_ticks_before(&mut self, before: Timestamp) {
+        for tick in self
+            .0
+            .db
+            .physics_ticks()
+            .iter()
+            .filter(|t| t.ticked_at < before)
+        {
+            self.0.db.physics_ticks().tick_id().delete(tick.tick_id);
+        }
+    }
+
+    fn schedule_respawn(&mut self, owner: Identity, at: Timestamp) {
+        let schedule = RespawnBallsSchedule {
+            scheduled_id: 0,
+            scheduled_at: at.into(),
+            respawn_for_identity: owner,
+        };
+        let res = self.0.db.respawn_balls_schedule().try_insert(schedule);
+        if let Err(err) = res {
+            log::error!("Failed to schedule respawn: {}", err);
+        }
+    }
+}
+
+/// A `GameStore` that keeps the tables in memory, to run the game logic without a database.
+/// Rows are kept ordered by their primary key. Nothing runs on its own: tests set `now` and call
+/// the scheduled functions of `game` themselves, `respawns` lists the scheduled respawns.
+pub struct MemoryStore {
+    pub now: Timestamp,
+    pub config: GameConfig,
+    pub balls: BTreeMap<u64, Ball>,
+    pub foods: BTreeMap<u64, Food>,
+    pub viruses: BTreeMap<u64, Virus>,
+    pub players: BTreeMap<Identity, Player>,
+    pub pending_inputs: BTreeMap<Identity, PendingInput>,
+    pub ticks: Vec<Timestamp>,
+    pub respawns: Vec<(Timestamp, Identity)>,
+    // the number of `update_ball` calls, to check what a tick writes
+    pub ball_updates: usize,
+    next_id: u64,
+    rng: physics::SplitMix64,
+}
+
+impl MemoryStore {
+    pub fn new(config: GameConfig, seed: u64) -> Self {
+        MemoryStore {
+            now: Timestamp::UNIX_EPOCH,
+            config,
+            balls: BTreeMap::new(),
+            foods: BTreeMap::new(),
+            viruses: BTreeMap::new(),
+            players: BTreeMap::new(),
+            pending_inputs: BTreeMap::new(),
+            ticks: Vec::new(),
+            respawns: Vec::new(),
+            ball_updates: 0,
+            next_id: 1,
+            rng: physics::SplitMix64(seed),
+        }
+    }
+
+    // shared by all tables, which makes ids easier to tell apart in test failures
+    fn next_id(&mut self) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    /// Removes and returns the owners whose respawn is due at `now`.
+    pub fn take_due_respawns(&mut self) -> Vec<Identity> {
+        let now = self.now;
+        let (due, later): (Vec<_>, Vec<_>) =
+            self.respawns.drain(..).partition(|(at, _)| *at <= now);
+        self.respawns = later;
+        due.into_iter().map(|(_, owner)| owner).collect()
+    }
+}
+
+impl physics::Rng for MemoryStore {
+    fn next_f64(&mut self) -> f64 {
+        self.rng.next_f64()
+    }
+}
+
+impl GameStore for MemoryStore {
+    fn now(&self) -> Timestamp {
+        self.now
+    }
+
+    fn config(&self) -> GameConfig {
+        self.config.clone()
+    }
+
+    fn balls(&self) -> Vec<Ball> {
+        self.balls.values().cloned().collect()
+    }
+
+    fn balls_of(&self, owner: Identity) -> Vec<Ball> {
+        self.balls
+            .values()
+            .filter(|b| b.owner == owner)
+            .cloned()
+            .collect()
+    }
+
+    fn insert_ball(&mut self, ball: Ball) {
+        let id = self.next_id();
+        self.balls.insert(id, Ball { id, ..ball });
+    }
+
+    fn update_ball(&mut self, ball: Ball) {
+        self.ball_updates += 1;
+        self.balls.insert(ball.id, ball);
+    }
+
+    fn delete_ball(&mut self, id: u64) {
+        self.balls.remove(&id);
+    }
+
+    fn foods(&self) -> Vec<Food> {
+        self.foods.values().cloned().collect()
+    }
+
+    fn food_count(&self) -> u64 {
+        self.foods.len() as u64
+    }
+
+    fn insert_food(&mut self, food: Food) {
+        let id = self.next_id();
+        self.foods.insert(id, Food { id, ..food });
+    }
+
+    fn update_food(&mut self, food: Food) {
+        self.foods.insert(food.id, food);
+    }
+
+    fn delete_food(&mut self, id: u64) {
+        self.foods.remove(&id);
+    }
+
+    fn viruses(&self) -> Vec<Virus> {
+        self.viruses.values().cloned().collect()
+    }
+
+    fn virus_count(&self) -> u64 {
+        self.viruses.len() as u64
+    }
+
+    fn insert_virus(&mut self, virus: Virus) {
+        let id = self.next_id();
+        self.viruses.insert(id, Virus { id, ..virus });
+    }
+
+    fn update_virus(&mut self, virus: Virus) {
+        self.viruses.insert(virus.id, virus);
+    }
+
+    fn delete_virus(&mut self, id: u64) {
+        self.viruses.remove(&id);
+    }
+
+    fn player(&self, identity: Identity) -> Option<Player> {
+        self.players.get(&identity).cloned()
+    }
+
+    fn insert_player(&mut self, player: Player) {
+        self.players.insert(player.identity, player);
+    }
+
+    fn update_player(&mut self, player: Player) {
+        self.players.insert(player.identity, player);
+    }
+
+    fn pending_inputs(&self) -> Vec<PendingInput> {
+        self.pending_inputs.values().cloned().collect()
+    }
+
+    fn pending_input(&self, owner: Identity) -> Option<PendingInput> {
+        self.pending_inputs.get(&owner).cloned()
+    }
+
+    fn insert_pending_input(&mut self, input: PendingInput) {
+        self.pending_inputs.insert(input.owner, input);
+    }
+
+    fn update_pending_input(&mut self, input: PendingInput) {
+        self.pending_inputs.insert(input.owner, input);
+    }
+
+    fn delete_pending_input(&mut self, owner: Identity) {
+        self.pending_inputs.remove(&owner);
+    }
+
+    fn latest_tick(&self) -> Option<Timestamp> {
+        self.ticks.iter().copied().max()
+    }
+
+    fn insert_tick(&mut self, ticked_at: Timestamp) {
+        self.ticks.push(ticked_at);
+    }
+
+    fn delete_ticks_before(&mut self, before: Timestamp) {
+        self.ticks.retain(|t| *t >= before);
+    }
+
+    fn schedule_respawn(&mut self, owner: Identity, at: Timestamp) {
+        self.respawns.push((at, owner));
+    }
+}
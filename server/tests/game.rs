@@ -0,0 +1,248 @@
+use spacetime_module::game;
+use spacetime_module::store::{GameStore, MemoryStore};
+use spacetime_module::{Ball, GameConfig, Player, Virus};
+use spacetimedb::{Identity, TimeDuration};
+
+fn identity(n: u8) -> Identity {
+    Identity::from_byte_array([n; 32])
+}
+
+fn store() -> MemoryStore {
+    MemoryStore::new(GameConfig::default(), 7)
+}
+
+// the only cell of `owner`
+fn cell(store: &MemoryStore, owner: Identity) -> Ball {
+    let cells = store.balls_of(owner);
+    assert_eq!(cells.len(), 1, "expected a single cell");
+    cells[0].clone()
+}
+
+fn place(store: &mut MemoryStore, owner: Identity, x: f64, y: f64, radius: f64) {
+    let ball = cell(store, owner);
+    store.update_ball(Ball {
+        x,
+        y,
+        radius,
+        vx: 0.0,
+        vy: 0.0,
+        ..ball
+    });
+}
+
+// moves the clock forward by one tick length and runs the tick, like the scheduler does
+fn tick(store: &mut MemoryStore) {
+    store.now = store.now + TimeDuration::from_micros(store.config.tick_micros);
+    game::tick(store);
+}
+
+/// Connects a big and a small player and lets the big one chase the small one for 100 ticks.
+fn big_chases_small(store: &mut MemoryStore) -> (Identity, Identity) {
+    let (big, small) = (identity(1), identity(2));
+    game::connect(store, big);
+    game::connect(store, small);
+    place(store, big, -30.0, 0.0, 20.0);
+    place(store, small, 30.0, 0.0, 4.0);
+
+    for seq in 1..=100 {
+        game::queue_input(store, big, seq, 200.0, 0.0);
+        tick(store);
+    }
+    (big, small)
+}
+
+#[test]
+fn bigger_ball_eats_smaller_one() {
+    let mut store = store();
+    let (big, small) = big_chases_small(&mut store);
+
+    assert!(cell(&store, small).dead);
+    assert!(cell(&store, big).radius > 20.0);
+    assert_eq!(store.player(big).unwrap().last_input_seq, 100);
+    assert_eq!(
+        store
+            .respawns
+            .iter()
+            .map(|(_, owner)| *owner)
+            .collect::<Vec<_>>(),
+        vec![small]
+    );
+}
+
+#[test]
+fn eaten_player_respawns() {
+    let mut store = store();
+    let (_, small) = big_chases_small(&mut store);
+    let dead_id = cell(&store, small).id;
+
+    assert!(store.take_due_respawns().is_empty());
+    store.now = store.now + TimeDuration::from_micros(store.config.respawn_micros);
+    let due = store.take_due_respawns();
+    assert_eq!(due, vec![small]);
+    for owner in due {
+        game::respawn_ball(&mut store, owner);
+    }
+
+    let respawned = cell(&store, small);
+    assert_eq!(respawned.id, dead_id);
+    assert!(!respawned.dead);
+    assert_eq!(respawned.radius, store.config.default_radius);
+}
+
+#[test]
+fn respawn_leaves_live_cells_alone() {
+    let mut store = store();
+    let player = identity(1);
+    game::connect(&mut store, player);
+    place(&mut store, player, 0.0, 0.0, 20.0);
+    game::split(&mut store, player, 1.0, 0.0);
+    let cells = store.balls_of(player);
+    assert_eq!(cells.len(), 2);
+    let (live, dead) = (cells[0].clone(), cells[1].clone());
+    store.update_ball(Ball {
+        dead: true,
+        ..dead.clone()
+    });
+
+    game::respawn_ball(&mut store, player);
+    assert_eq!(store.balls[&live.id].radius, live.radius);
+    let respawned = &store.balls[&dead.id];
+    assert!(!respawned.dead);
+    assert_eq!(respawned.radius, store.config.default_radius);
+
+    // nothing dead is left to respawn
+    game::respawn_ball(&mut store, player);
+    assert_eq!(store.balls[&live.id].radius, live.radius);
+}
+
+#[test]
+fn split_with_huge_aim_keeps_its_direction() {
+    let mut store = store();
+    let player = identity(1);
+    game::connect(&mut store, player);
+    place(&mut store, player, 0.0, 0.0, 20.0);
+    // the square of the aim overflows
+    game::split(&mut store, player, 1e200, 0.0);
+    let cells = store.balls_of(player);
+    assert_eq!(cells.len(), 2);
+    assert!(cells.iter().all(|b| b.x.is_finite() && b.vx.is_finite()));
+    assert!(cells.iter().any(|b| b.vx > 0.0));
+}
+
+#[test]
+fn out_of_order_inputs_are_dropped() {
+    let mut store = store();
+    let player = identity(1);
+    game::connect(&mut store, player);
+
+    game::queue_input(&mut store, player, 2, 10.0, 0.0);
+    game::queue_input(&mut store, player, 1, 0.0, 10.0);
+    assert_eq!(store.pending_input(player).unwrap().seq, 2);
+
+    tick(&mut store);
+    assert!(store.pending_inputs().is_empty());
+    assert_eq!(store.player(player).unwrap().last_input_seq, 2);
+    assert!(cell(&store, player).vx > 0.0);
+
+    // already applied
+    game::queue_input(&mut store, player, 2, 10.0, 0.0);
+    assert!(store.pending_input(player).is_none());
+}
+
+#[test]
+fn disconnect_removes_all_cells() {
+    let mut store = store();
+    let player = identity(1);
+    game::connect(&mut store, player);
+    game::queue_input(&mut store, player, 5, 10.0, 0.0);
+    tick(&mut store);
+    place(&mut store, player, 0.0, 0.0, 20.0);
+    game::split(&mut store, player, 1.0, 0.0);
+    assert_eq!(store.balls_of(player).len(), 2);
+
+    let profile = store.player(player).unwrap();
+    store.update_player(Player {
+        name: "alice".to_string(),
+        ..profile
+    });
+
+    game::disconnect(&mut store, player);
+    assert!(store.balls_of(player).is_empty());
+    let offline = store.player(player).unwrap();
+    assert!(!offline.online);
+    assert_ne!(offline.name, "alice", "offline players release their name");
+
+    // returning players keep their profile and get a fresh cell
+    game::connect(&mut store, player);
+    let returned = store.player(player).unwrap();
+    assert!(returned.online);
+    assert_eq!(returned.last_input_seq, 0);
+    assert_eq!(cell(&store, player).radius, store.config.default_radius);
+}
+
+#[test]
+fn spawn_food_stops_at_max_foods() {
+    let mut store = store();
+    for _ in 0..20 {
+        game::spawn_food(&mut store);
+    }
+    assert_eq!(store.food_count(), store.config.max_foods);
+    assert_eq!(store.virus_count(), Virus::MIN_VIRUSES);
+}
+
+#[test]
+fn resting_balls_are_not_written() {
+    let mut store = store();
+    let (resting, pushed) = (identity(1), identity(2));
+    game::connect(&mut store, resting);
+    game::connect(&mut store, pushed);
+    place(&mut store, resting, -50.0, 0.0, 4.0);
+    place(&mut store, pushed, 50.0, 0.0, 4.0);
+    store.ball_updates = 0;
+
+    game::queue_input(&mut store, pushed, 1, 100.0, 0.0);
+    tick(&mut store);
+    assert_eq!(store.ball_updates, 1, "only the pushed ball changed");
+
+    // the pushed ball slows down until it falls asleep, then nothing is written anymore
+    for _ in 0..300 {
+        tick(&mut store);
+    }
+    assert_eq!(cell(&store, pushed).vx, 0.0);
+    let updates = store.ball_updates;
+    tick(&mut store);
+    assert_eq!(store.ball_updates, updates);
+}
+
+#[test]
+fn config_rejects_nan_and_inverted_borders() {
+    assert!(GameConfig::default().validate().is_ok());
+    let nan_border = GameConfig {
+        world_border_max_x: f64::NAN,
+        ..GameConfig::default()
+    };
+    assert!(nan_border.validate().is_err());
+    let inverted = GameConfig {
+        world_border_min_y: 200.0,
+        world_border_max_y: -200.0,
+        ..GameConfig::default()
+    };
+    assert!(inverted.validate().is_err());
+}
+
+#[test]
+fn late_tick_simulates_up_to_the_max_delta() {
+    let mut store = store();
+    let player = identity(1);
+    game::connect(&mut store, player);
+    place(&mut store, player, 0.0, 0.0, 4.0);
+    game::queue_input(&mut store, player, 1, 100.0, 0.0);
+    tick(&mut store);
+
+    // a whole second late, but only 250ms are simulated, not a regular tick length
+    let before = cell(&store, player);
+    store.now = store.now + TimeDuration::from_micros(1_000_000);
+    game::tick(&mut store);
+    let moved = cell(&store, player).x - before.x;
+    assert!(moved > 10.0 && moved < before.vx * 0.25, "moved {moved}");
+}